@@ -2,7 +2,7 @@ use std::fs;
 use std::io::Write;
 
 fn main() {
-    let code = modbus_mapping::generate_registers("./registers.json");
+    let code = modbus_mapping::generate_registers("./registers.json", true);
     let syntax_tree = syn::parse2(code).unwrap();
     let formatted = prettyplease::unparse(&syntax_tree);
 
@@ -13,7 +13,10 @@ struct EnumData {
     description: String,
     reg: u16,
     #[serde(rename = "enum")]
-    enum_values: Option<HashMap<String, u16>>
+    enum_values: Option<HashMap<String, u16>>,
+    /// Write access mode: `"rw"` (default) or `"ro"`. Read-only registers
+    /// reject writes in the generated `ToModbusRegisters` impl.
+    access: Option<String>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -24,6 +27,22 @@ struct FloatData {
     #[allow(unused)]
     data_type: Option<String>,
     gain: Option<f32>,
+    /// Number of 16-bit registers this value spans: `1` (raw register scaled by
+    /// `gain`, the default) or `2` (registers combined per `word_order`).
+    words: Option<u8>,
+    /// Word order for `words: 2`: `"big"` (default, high word first) or `"little"`.
+    word_order: Option<String>,
+    /// Added after scaling: `value = raw * gain + offset`.
+    offset: Option<f32>,
+    /// Unit suffix appended by the generated `Display` impl, e.g. `"°C"`.
+    unit: Option<String>,
+    /// Write access mode: `"rw"` (default) or `"ro"`. Read-only registers
+    /// reject writes in the generated `ToModbusRegisters` impl.
+    access: Option<String>,
+    /// Inclusive lower bound enforced by the generated `ToModbusRegisters` impl.
+    min: Option<f32>,
+    /// Inclusive upper bound enforced by the generated `ToModbusRegisters` impl.
+    max: Option<f32>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -33,6 +52,16 @@ struct SignedData {
     #[serde(rename = "type")]
     #[allow(unused)]
     data_type: Option<String>,
+    scale: Option<f32>,
+    /// Unit suffix appended by the generated `Display` impl, e.g. `"°C"`.
+    unit: Option<String>,
+    /// Write access mode: `"rw"` (default) or `"ro"`. Read-only registers
+    /// reject writes in the generated `ToModbusRegisters` impl.
+    access: Option<String>,
+    /// Inclusive lower bound enforced by the generated `ToModbusRegisters` impl.
+    min: Option<f32>,
+    /// Inclusive upper bound enforced by the generated `ToModbusRegisters` impl.
+    max: Option<f32>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -42,6 +71,26 @@ struct UnsignedShortData {
     #[serde(rename = "type")]
     #[allow(unused)]
     data_type: Option<String>,
+    /// Unit suffix appended by the generated `Display` impl, e.g. `"°C"`.
+    unit: Option<String>,
+    /// Write access mode: `"rw"` (default) or `"ro"`. Read-only registers
+    /// reject writes in the generated `ToModbusRegisters` impl.
+    access: Option<String>,
+    /// Inclusive lower bound enforced by the generated `ToModbusRegisters` impl.
+    min: Option<f32>,
+    /// Inclusive upper bound enforced by the generated `ToModbusRegisters` impl.
+    max: Option<f32>,
+}
+
+#[derive(Debug, Deserialize)]
+struct LongData {
+    description: String,
+    reg: u16,
+    #[serde(rename = "type")]
+    #[allow(unused)]
+    data_type: Option<String>,
+    swap_words: Option<bool>,
+    scale: Option<f32>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -61,6 +110,10 @@ enum HoldingRegister {
     Float(FloatData),
     #[serde(rename = "i8")]
     SignedChar(SignedData),
+    #[serde(rename = "u32")]
+    UnsignedLong(LongData),
+    #[serde(rename = "s32")]
+    SignedLong(LongData),
 }
 
 #[derive(Debug, Deserialize)]
@@ -73,7 +126,11 @@ enum InputRegister {
     #[serde(rename = "i8")]
     SignedChar(SignedData),
     #[serde(rename = "u16")]
-    UnsignedShort(UnsignedShortData)
+    UnsignedShort(UnsignedShortData),
+    #[serde(rename = "u32")]
+    UnsignedLong(LongData),
+    #[serde(rename = "s32")]
+    SignedLong(LongData),
 }
 
 #[derive(Debug, Deserialize)]
@@ -116,10 +173,35 @@ fn sanitize_identifier(name: &str) -> String {
     words.concat().replace('_', "")
 }
 
-pub fn generate_registers(modbus_register_data_file_path: &str) -> TokenStream {
+/// Resolves a schema `access` string to a read-only flag, defaulting to
+/// writable (`"rw"`) when absent. Panics on anything other than `"ro"`/`"rw"`
+/// so a typo in `registers.json` is caught at generation time rather than
+/// silently treated as writable.
+fn read_only_from_access(access: &Option<String>, description: &str) -> bool {
+    match access.as_deref() {
+        None | Some("rw") => false,
+        Some("ro") => true,
+        Some(other) => panic!(
+            "register '{description}' has invalid access mode '{other}', expected \"ro\" or \"rw\""
+        ),
+    }
+}
+
+/// Generates the `registers` module from a JSON schema. When `derive_serialize`
+/// is set, every generated type also gets a `serde::Serialize` impl so register
+/// values can be published as JSON (e.g. over MQTT): numeric newtypes serialize
+/// to their inner value, coil/discrete types to their configured true/false
+/// display string, and enums to their variant name (`Unknown` to the raw
+/// register value).
+pub fn generate_registers(modbus_register_data_file_path: &str, derive_serialize: bool) -> TokenStream {
     // Read the JSON file
     let json_data = fs::read_to_string(modbus_register_data_file_path).expect("Unable to read JSON file");
     let parsed: Registers = serde_json::from_str(&json_data).expect("Invalid JSON format");
+    let serialize_derive = if derive_serialize {
+        quote! { #[derive(serde::Serialize)] }
+    } else {
+        quote! {}
+    };
 
     let mut holding_generated_enums: Vec<TokenStream> = Vec::new();
     let mut holding_generated_structs: Vec<TokenStream> = Vec::new();
@@ -127,6 +209,10 @@ pub fn generate_registers(modbus_register_data_file_path: &str) -> TokenStream {
     let mut discrete_generated_structs: Vec<TokenStream> = Vec::new();
     let mut input_generated_enums: Vec<TokenStream> = Vec::new();
     let mut input_generated_structs: Vec<TokenStream> = Vec::new();
+    let mut holding_descriptors: Vec<TokenStream> = Vec::new();
+    let mut coil_descriptors: Vec<TokenStream> = Vec::new();
+    let mut discrete_descriptors: Vec<TokenStream> = Vec::new();
+    let mut input_descriptors: Vec<TokenStream> = Vec::new();
 
     for entry in parsed.holding {
         match entry {
@@ -137,35 +223,38 @@ pub fn generate_registers(modbus_register_data_file_path: &str) -> TokenStream {
                 );
 
                 let reg_value = reg.reg;
+                let description = reg.description.clone();
+                let read_only = read_only_from_access(&reg.access, &description);
                 if let Some(reg) = reg.enum_values {
-                    generate_enum(&mut holding_generated_enums, name, reg, reg_value);
+                    generate_enum(
+                        &mut holding_generated_enums,
+                        name,
+                        reg,
+                        reg_value,
+                        derive_serialize,
+                        &mut holding_descriptors,
+                        &description,
+                        quote! { RegisterKind::Holding },
+                        read_only,
+                    );
                 }
-            },
+            }
             HoldingRegister::Float(reg) => {
                 let name = syn::Ident::new(
                     &sanitize_identifier(&reg.description),
                     proc_macro2::Span::call_site(),
                 );
 
-                let gain_value: f32 = reg.gain.unwrap_or(1f32);
-                let reg_value = reg.reg;
-
-                holding_generated_structs.push(quote! {
-                    #[allow(unused)]
-                    #[derive(Debug)]
-                    pub struct #name(f32);
-
-                    impl ModbusRegister<Vec<u16>> for #name {
-                        fn reg() -> u16 { #reg_value }
-                    }
-
-                    impl From<Vec<u16>> for #name {
-                        fn from(value: Vec<u16>) -> Self {
-                            #name(value[0] as f32 * #gain_value)
-                        }
-                    }
-                });
-
+                let description = reg.description.clone();
+                generate_float(
+                    &mut holding_generated_structs,
+                    name,
+                    reg,
+                    &serialize_derive,
+                    &mut holding_descriptors,
+                    &description,
+                    quote! { RegisterKind::Holding },
+                );
             }
             HoldingRegister::SignedChar(reg) => {
                 let name = syn::Ident::new(
@@ -173,26 +262,72 @@ pub fn generate_registers(modbus_register_data_file_path: &str) -> TokenStream {
                     proc_macro2::Span::call_site(),
                 );
                 let reg_value = reg.reg;
+                let description = reg.description.clone();
+                let unit = reg.unit.clone().unwrap_or_default();
+                let read_only = read_only_from_access(&reg.access, &description);
 
-                holding_generated_structs.push(quote! {
-                    #[allow(unused)]
-                    #[derive(Debug)]
-                    pub struct #name(i16);
+                generate_numeric_short(
+                    &mut holding_generated_structs,
+                    name,
+                    reg_value,
+                    reg.scale,
+                    quote! { i16 },
+                    syn::Ident::new("register_to_i16", proc_macro2::Span::call_site()),
+                    syn::Ident::new("i16_to_register", proc_macro2::Span::call_site()),
+                    &serialize_derive,
+                    &mut holding_descriptors,
+                    &description,
+                    quote! { RegisterKind::Holding },
+                    quote! { Signed },
+                    &unit,
+                    read_only,
+                    reg.min,
+                    reg.max,
+                );
+            }
+            HoldingRegister::UnsignedLong(reg) => {
+                let name = syn::Ident::new(
+                    &sanitize_identifier(&reg.description),
+                    proc_macro2::Span::call_site(),
+                );
+                let description = reg.description.clone();
 
-                    impl #name {
-                        pub fn reg() -> u16 { #reg_value }
-                    }
+                generate_long(
+                    &mut holding_generated_structs,
+                    name,
+                    reg.reg,
+                    reg.swap_words.unwrap_or(false),
+                    reg.scale,
+                    false,
+                    &serialize_derive,
+                    &mut holding_descriptors,
+                    &description,
+                    quote! { RegisterKind::Holding },
+                );
+            }
+            HoldingRegister::SignedLong(reg) => {
+                let name = syn::Ident::new(
+                    &sanitize_identifier(&reg.description),
+                    proc_macro2::Span::call_site(),
+                );
+                let description = reg.description.clone();
 
-                    impl From<Vec<u16>> for #name {
-                        fn from(value: Vec<u16>) -> Self {
-                            #name(super::register_to_i16(value))
-                        }
-                    }
-                });
+                generate_long(
+                    &mut holding_generated_structs,
+                    name,
+                    reg.reg,
+                    reg.swap_words.unwrap_or(false),
+                    reg.scale,
+                    true,
+                    &serialize_derive,
+                    &mut holding_descriptors,
+                    &description,
+                    quote! { RegisterKind::Holding },
+                );
             }
         }
     }
-    
+
     for entry in parsed.input {
         match entry {
             InputRegister::Enum(reg) => {
@@ -202,8 +337,20 @@ pub fn generate_registers(modbus_register_data_file_path: &str) -> TokenStream {
                 );
 
                 let reg_value = reg.reg;
+                let description = reg.description.clone();
+                let read_only = read_only_from_access(&reg.access, &description);
                 if let Some(reg) = reg.enum_values {
-                    generate_enum(&mut input_generated_enums, name, reg, reg_value);
+                    generate_enum(
+                        &mut input_generated_enums,
+                        name,
+                        reg,
+                        reg_value,
+                        derive_serialize,
+                        &mut input_descriptors,
+                        &description,
+                        quote! { RegisterKind::Input },
+                        read_only,
+                    );
                 }
             }
             InputRegister::Float(reg) => {
@@ -212,49 +359,115 @@ pub fn generate_registers(modbus_register_data_file_path: &str) -> TokenStream {
                     proc_macro2::Span::call_site(),
                 );
 
-                let gain_value: f32 = reg.gain.unwrap_or(1f32);
+                let description = reg.description.clone();
+                generate_float(
+                    &mut input_generated_structs,
+                    name,
+                    reg,
+                    &serialize_derive,
+                    &mut input_descriptors,
+                    &description,
+                    quote! { RegisterKind::Input },
+                );
+            }
+            InputRegister::SignedChar(reg) => {
+                let name = syn::Ident::new(
+                    &sanitize_identifier(&reg.description),
+                    proc_macro2::Span::call_site(),
+                );
                 let reg_value = reg.reg;
+                let description = reg.description.clone();
+                let unit = reg.unit.clone().unwrap_or_default();
+                let read_only = read_only_from_access(&reg.access, &description);
 
-                input_generated_structs.push(quote! {
-                    #[allow(unused)]
-                    #[derive(Debug)]
-                    pub struct #name(f32);
-
-                    impl ModbusRegister<Vec<u16>> for #name {
-                        fn reg() -> u16 { #reg_value }
-                    }
-
-                    impl From<Vec<u16>> for #name {
-                        fn from(value: Vec<u16>) -> Self {
-                            #name(value[0] as f32 * #gain_value)
-                        }
-                    }
-                });
+                generate_numeric_short(
+                    &mut input_generated_structs,
+                    name,
+                    reg_value,
+                    reg.scale,
+                    quote! { i16 },
+                    syn::Ident::new("register_to_i16", proc_macro2::Span::call_site()),
+                    syn::Ident::new("i16_to_register", proc_macro2::Span::call_site()),
+                    &serialize_derive,
+                    &mut input_descriptors,
+                    &description,
+                    quote! { RegisterKind::Input },
+                    quote! { Signed },
+                    &unit,
+                    read_only,
+                    reg.min,
+                    reg.max,
+                );
             }
-            InputRegister::SignedChar(reg) => {
+            InputRegister::UnsignedShort(reg) => {
                 let name = syn::Ident::new(
                     &sanitize_identifier(&reg.description),
                     proc_macro2::Span::call_site(),
                 );
                 let reg_value = reg.reg;
+                let description = reg.description.clone();
+                let unit = reg.unit.clone().unwrap_or_default();
+                let read_only = read_only_from_access(&reg.access, &description);
 
-                input_generated_structs.push(quote! {
-                    #[allow(unused)]
-                    #[derive(Debug)]
-                    pub struct #name(i16);
+                generate_numeric_short(
+                    &mut input_generated_structs,
+                    name,
+                    reg_value,
+                    None,
+                    quote! { u16 },
+                    syn::Ident::new("register_to_u16", proc_macro2::Span::call_site()),
+                    syn::Ident::new("u16_to_register", proc_macro2::Span::call_site()),
+                    &serialize_derive,
+                    &mut input_descriptors,
+                    &description,
+                    quote! { RegisterKind::Input },
+                    quote! { Unsigned },
+                    &unit,
+                    read_only,
+                    reg.min,
+                    reg.max,
+                );
+            }
+            InputRegister::UnsignedLong(reg) => {
+                let name = syn::Ident::new(
+                    &sanitize_identifier(&reg.description),
+                    proc_macro2::Span::call_site(),
+                );
+                let description = reg.description.clone();
 
-                    impl #name {
-                        pub fn reg() -> u16 { #reg_value }
-                    }
+                generate_long(
+                    &mut input_generated_structs,
+                    name,
+                    reg.reg,
+                    reg.swap_words.unwrap_or(false),
+                    reg.scale,
+                    false,
+                    &serialize_derive,
+                    &mut input_descriptors,
+                    &description,
+                    quote! { RegisterKind::Input },
+                );
+            }
+            InputRegister::SignedLong(reg) => {
+                let name = syn::Ident::new(
+                    &sanitize_identifier(&reg.description),
+                    proc_macro2::Span::call_site(),
+                );
+                let description = reg.description.clone();
 
-                    impl From<Vec<u16>> for #name {
-                        fn from(value: Vec<u16>) -> Self {
-                            #name(super::register_to_i16(value))
-                        }
-                    }
-                });
+                generate_long(
+                    &mut input_generated_structs,
+                    name,
+                    reg.reg,
+                    reg.swap_words.unwrap_or(false),
+                    reg.scale,
+                    true,
+                    &serialize_derive,
+                    &mut input_descriptors,
+                    &description,
+                    quote! { RegisterKind::Input },
+                );
             }
-            InputRegister::UnsignedShort(reg) => {}
         }
     }
 
@@ -265,8 +478,32 @@ pub fn generate_registers(modbus_register_data_file_path: &str) -> TokenStream {
         );
 
         let reg_value = entry.reg;
+        let description = entry.description.clone();
         let true_value = entry.values.r#true;
         let false_value = entry.values.r#false;
+        let serialize_impl = if derive_serialize {
+            quote! {
+                impl serde::Serialize for #name {
+                    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                    where
+                        S: serde::Serializer,
+                    {
+                        serializer.serialize_str(self.as_str())
+                    }
+                }
+            }
+        } else {
+            quote! {}
+        };
+
+        coil_descriptors.push(quote! {
+            RegisterDescriptor {
+                address: #reg_value,
+                name: #description,
+                kind: RegisterKind::Coil,
+                decode: |value| DecodedValue::Bool(value.first().copied().unwrap_or(0) != 0),
+            },
+        });
 
         coil_generated_structs.push(quote! {
             pub struct #name(bool);
@@ -296,6 +533,14 @@ pub fn generate_registers(modbus_register_data_file_path: &str) -> TokenStream {
                     write!(f, "{}", self.as_str())
                 }
             }
+
+            impl ToModbusCoil for #name {
+                fn to_coil(&self) -> bool {
+                    self.0
+                }
+            }
+
+            #serialize_impl
         })
     }
 
@@ -306,8 +551,32 @@ pub fn generate_registers(modbus_register_data_file_path: &str) -> TokenStream {
         );
 
         let reg_value = entry.reg;
+        let description = entry.description.clone();
         let true_value = entry.values.r#true;
         let false_value = entry.values.r#false;
+        let serialize_impl = if derive_serialize {
+            quote! {
+                impl serde::Serialize for #name {
+                    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                    where
+                        S: serde::Serializer,
+                    {
+                        serializer.serialize_str(self.as_str())
+                    }
+                }
+            }
+        } else {
+            quote! {}
+        };
+
+        discrete_descriptors.push(quote! {
+            RegisterDescriptor {
+                address: #reg_value,
+                name: #description,
+                kind: RegisterKind::Discrete,
+                decode: |value| DecodedValue::Bool(value.first().copied().unwrap_or(0) != 0),
+            },
+        });
 
         discrete_generated_structs.push(quote! {
             pub struct #name(bool);
@@ -337,6 +606,8 @@ pub fn generate_registers(modbus_register_data_file_path: &str) -> TokenStream {
                     write!(f, "{}", self.as_str())
                 }
             }
+
+            #serialize_impl
         })
     }
 
@@ -347,54 +618,520 @@ pub fn generate_registers(modbus_register_data_file_path: &str) -> TokenStream {
             .flat_map(|&w| [(w >> 8) as u8, w as u8])
             .collect::<Vec<_>>()
         }
-        pub fn register_to_f32(data: Vec<u16>) -> f32 {
+        pub fn register_to_f32(data: Vec<u16>, swap_words: bool) -> f32 {
+            assert_eq!(data.len(), 2, "f32 register decode expects exactly 2 registers, got {}", data.len());
+            let data = if swap_words { vec![data[1], data[0]] } else { data };
             f32::from_be_bytes(register_to_bytes(data).try_into().unwrap())
         }
         pub fn register_to_u16(data: Vec<u16>) -> u16 {
+            assert_eq!(data.len(), 1, "u16 register decode expects exactly 1 register, got {}", data.len());
             u16::from_be_bytes(register_to_bytes(data).try_into().unwrap())
         }
         pub fn register_to_i16(data: Vec<u16>) -> i16 {
+            assert_eq!(data.len(), 1, "i16 register decode expects exactly 1 register, got {}", data.len());
             i16::from_be_bytes(register_to_bytes(data).try_into().unwrap())
         }
+        pub fn register_to_u32(data: Vec<u16>, swap_words: bool) -> u32 {
+            assert_eq!(data.len(), 2, "u32 register decode expects exactly 2 registers, got {}", data.len());
+            let data = if swap_words { vec![data[1], data[0]] } else { data };
+            u32::from_be_bytes(register_to_bytes(data).try_into().unwrap())
+        }
+        pub fn register_to_i32(data: Vec<u16>, swap_words: bool) -> i32 {
+            register_to_u32(data, swap_words) as i32
+        }
+        pub fn u16_to_register(value: u16) -> Vec<u16> {
+            vec![value]
+        }
+        pub fn i16_to_register(value: i16) -> Vec<u16> {
+            vec![value as u16]
+        }
+        pub fn f32_to_register(value: f32, swap_words: bool) -> Vec<u16> {
+            let bytes = value.to_be_bytes();
+            let data = vec![
+                u16::from_be_bytes([bytes[0], bytes[1]]),
+                u16::from_be_bytes([bytes[2], bytes[3]]),
+            ];
+            if swap_words { vec![data[1], data[0]] } else { data }
+        }
+        pub fn u32_to_register(value: u32, swap_words: bool) -> Vec<u16> {
+            let data = vec![(value >> 16) as u16, value as u16];
+            if swap_words { vec![data[1], data[0]] } else { data }
+        }
+        pub fn i32_to_register(value: i32, swap_words: bool) -> Vec<u16> {
+            u32_to_register(value as u32, swap_words)
+        }
 
         pub trait ModbusRegister<T> : From<T> {
             fn reg() -> u16;
         }
 
+        pub trait ToModbusRegisters {
+            /// Encodes `self` to the registers that would write it back, or
+            /// `Err` if the register is read-only or the value falls outside
+            /// its configured `min`/`max` bounds.
+            fn to_registers(&self) -> Result<Vec<u16>, String>;
+        }
+
+        pub trait ToModbusCoil {
+            fn to_coil(&self) -> bool;
+        }
+
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        pub enum RegisterKind {
+            Coil,
+            Discrete,
+            Holding,
+            Input,
+        }
+
+        #[derive(Debug, Clone, Copy)]
+        pub enum DecodedValue {
+            Float(f32),
+            Signed(i16),
+            Unsigned(u16),
+            Enum(&'static str),
+            Bool(bool),
+        }
+
+        pub struct RegisterDescriptor {
+            pub address: u16,
+            pub name: &'static str,
+            pub kind: RegisterKind,
+            pub decode: fn(Vec<u16>) -> DecodedValue,
+        }
+
+        /// Looks up the descriptor for `address` across all register classes.
+        /// Addresses are only unique within a class, so the first match across
+        /// coil, discrete, holding, then input wins.
+        pub fn lookup(address: u16) -> Option<&'static RegisterDescriptor> {
+            coil::REGISTERS
+                .iter()
+                .chain(discrete::REGISTERS.iter())
+                .chain(holding::REGISTERS.iter())
+                .chain(input::REGISTERS.iter())
+                .find(|descriptor| descriptor.address == address)
+        }
+
         pub mod coil {
             use std::fmt;
             use crate::registers::ModbusRegister;
+            use crate::registers::ToModbusCoil;
+            use crate::registers::{DecodedValue, RegisterDescriptor, RegisterKind};
             #(#coil_generated_structs)*
+
+            pub static REGISTERS: &[RegisterDescriptor] = &[#(#coil_descriptors)*];
         }
 
         pub mod discrete {
             use std::fmt;
             use crate::registers::ModbusRegister;
+            use crate::registers::{DecodedValue, RegisterDescriptor, RegisterKind};
             #(#discrete_generated_structs)*
+
+            pub static REGISTERS: &[RegisterDescriptor] = &[#(#discrete_descriptors)*];
         }
-        
+
         pub mod input{
+            use std::fmt;
             use crate::registers::ModbusRegister;
+            use crate::registers::ToModbusRegisters;
+            use crate::registers::{DecodedValue, RegisterDescriptor, RegisterKind};
             #(#input_generated_enums)*
             #(#input_generated_structs)*
+
+            pub static REGISTERS: &[RegisterDescriptor] = &[#(#input_descriptors)*];
         }
 
         pub mod holding{
+            use std::fmt;
             use crate::registers::ModbusRegister;
+            use crate::registers::ToModbusRegisters;
+            use crate::registers::{DecodedValue, RegisterDescriptor, RegisterKind};
             #(#holding_generated_enums)*
             #(#holding_generated_structs)*
+
+            pub static REGISTERS: &[RegisterDescriptor] = &[#(#holding_descriptors)*];
+        }
+    }
+}
+
+fn generate_float(
+    generated_structs: &mut Vec<TokenStream>,
+    name: Ident,
+    reg: FloatData,
+    serialize_derive: &TokenStream,
+    descriptors: &mut Vec<TokenStream>,
+    description: &str,
+    kind: TokenStream,
+) {
+    let reg_value = reg.reg;
+    let gain_value: f32 = reg.gain.unwrap_or(1f32);
+    let offset_value: f32 = reg.offset.unwrap_or(0f32);
+    let swap_words = reg.word_order.as_deref() == Some("little");
+    let unit = reg.unit.clone().unwrap_or_default();
+    let read_only = read_only_from_access(&reg.access, description);
+    let write_guard = write_guard(read_only, reg.min, reg.max, description);
+
+    descriptors.push(quote! {
+        RegisterDescriptor {
+            address: #reg_value,
+            name: #description,
+            kind: #kind,
+            decode: |value| DecodedValue::Float(#name::from(value).0),
+        },
+    });
+
+    generated_structs.push(match reg.words.unwrap_or(1) {
+        2 => quote! {
+            #[allow(unused)]
+            #[derive(Debug)]
+            #serialize_derive
+            pub struct #name(f32);
+
+            impl #name {
+                pub const UNIT: &'static str = #unit;
+                pub const READ_ONLY: bool = #read_only;
+            }
+
+            impl ModbusRegister<Vec<u16>> for #name {
+                fn reg() -> u16 { #reg_value }
+            }
+
+            impl From<Vec<u16>> for #name {
+                fn from(value: Vec<u16>) -> Self {
+                    #name(super::register_to_f32(value, #swap_words) * #gain_value + #offset_value)
+                }
+            }
+
+            impl fmt::Display for #name {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(f, "{}{}", self.0, Self::UNIT)
+                }
+            }
+
+            impl ToModbusRegisters for #name {
+                fn to_registers(&self) -> Result<Vec<u16>, String> {
+                    #write_guard
+                    Ok(super::f32_to_register((self.0 - #offset_value) / #gain_value, #swap_words))
+                }
+            }
+        },
+        _ => quote! {
+            #[allow(unused)]
+            #[derive(Debug)]
+            #serialize_derive
+            pub struct #name(f32);
+
+            impl #name {
+                pub const UNIT: &'static str = #unit;
+                pub const READ_ONLY: bool = #read_only;
+            }
+
+            impl ModbusRegister<Vec<u16>> for #name {
+                fn reg() -> u16 { #reg_value }
+            }
+
+            impl From<Vec<u16>> for #name {
+                fn from(value: Vec<u16>) -> Self {
+                    #name(value[0] as f32 * #gain_value + #offset_value)
+                }
+            }
+
+            impl fmt::Display for #name {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(f, "{}{}", self.0, Self::UNIT)
+                }
+            }
+
+            impl ToModbusRegisters for #name {
+                fn to_registers(&self) -> Result<Vec<u16>, String> {
+                    #write_guard
+                    Ok(vec![((self.0 - #offset_value) / #gain_value) as u16])
+                }
+            }
+        },
+    });
+}
+
+/// Builds the bounds/read-only guard shared by every `ToModbusRegisters`
+/// impl: an early `return Err(..)` when the register is read-only or `self.0`
+/// (cast to `f32`) falls outside `[min, max]`. Expands to nothing for a
+/// writable, unbounded register.
+fn write_guard(
+    read_only: bool,
+    min: Option<f32>,
+    max: Option<f32>,
+    description: &str,
+) -> TokenStream {
+    let read_only_check = if read_only {
+        quote! {
+            return Err(format!("{} is read-only", #description));
         }
+    } else {
+        quote! {}
+    };
+    let min_check = match min {
+        Some(min_value) => quote! {
+            if (self.0 as f32) < #min_value {
+                return Err(format!("{} is below minimum {}", #description, #min_value));
+            }
+        },
+        None => quote! {},
+    };
+    let max_check = match max {
+        Some(max_value) => quote! {
+            if (self.0 as f32) > #max_value {
+                return Err(format!("{} is above maximum {}", #description, #max_value));
+            }
+        },
+        None => quote! {},
+    };
+    quote! {
+        #read_only_check
+        #min_check
+        #max_check
     }
 }
 
+/// Shared codegen for single-register numeric types (`i16`/`u16`), optionally
+/// scaled to `f32`. `raw_type`/`decode_fn`/`encode_fn` select the unscaled Rust
+/// representation and its `register_to_*`/`*_to_register` converters, so this
+/// one routine backs both `SignedChar` and `UnsignedShort` entries without
+/// duplicating the quote! template per type.
+fn generate_numeric_short(
+    generated_structs: &mut Vec<TokenStream>,
+    name: Ident,
+    reg_value: u16,
+    scale: Option<f32>,
+    raw_type: TokenStream,
+    decode_fn: Ident,
+    encode_fn: Ident,
+    serialize_derive: &TokenStream,
+    descriptors: &mut Vec<TokenStream>,
+    description: &str,
+    kind: TokenStream,
+    unscaled_variant: TokenStream,
+    unit: &str,
+    read_only: bool,
+    min: Option<f32>,
+    max: Option<f32>,
+) {
+    let write_guard = write_guard(read_only, min, max, description);
+
+    descriptors.push(match scale {
+        Some(_) => quote! {
+            RegisterDescriptor {
+                address: #reg_value,
+                name: #description,
+                kind: #kind,
+                decode: |value| DecodedValue::Float(#name::from(value).0),
+            },
+        },
+        None => quote! {
+            RegisterDescriptor {
+                address: #reg_value,
+                name: #description,
+                kind: #kind,
+                decode: |value| DecodedValue::#unscaled_variant(#name::from(value).0),
+            },
+        },
+    });
+
+    generated_structs.push(match scale {
+        Some(scale_value) => quote! {
+            #[allow(unused)]
+            #[derive(Debug)]
+            #serialize_derive
+            pub struct #name(f32);
+
+            impl #name {
+                pub fn reg() -> u16 { #reg_value }
+                pub const UNIT: &'static str = #unit;
+                pub const READ_ONLY: bool = #read_only;
+            }
+
+            impl From<Vec<u16>> for #name {
+                fn from(value: Vec<u16>) -> Self {
+                    #name(super::#decode_fn(value) as f32 * #scale_value)
+                }
+            }
+
+            impl fmt::Display for #name {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(f, "{}{}", self.0, Self::UNIT)
+                }
+            }
+
+            impl super::ToModbusRegisters for #name {
+                fn to_registers(&self) -> Result<Vec<u16>, String> {
+                    #write_guard
+                    Ok(super::#encode_fn((self.0 / #scale_value) as #raw_type))
+                }
+            }
+        },
+        None => quote! {
+            #[allow(unused)]
+            #[derive(Debug)]
+            #serialize_derive
+            pub struct #name(#raw_type);
+
+            impl #name {
+                pub fn reg() -> u16 { #reg_value }
+                pub const UNIT: &'static str = #unit;
+                pub const READ_ONLY: bool = #read_only;
+            }
+
+            impl From<Vec<u16>> for #name {
+                fn from(value: Vec<u16>) -> Self {
+                    #name(super::#decode_fn(value))
+                }
+            }
+
+            impl fmt::Display for #name {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    write!(f, "{}{}", self.0, Self::UNIT)
+                }
+            }
+
+            impl super::ToModbusRegisters for #name {
+                fn to_registers(&self) -> Result<Vec<u16>, String> {
+                    #write_guard
+                    Ok(super::#encode_fn(self.0))
+                }
+            }
+        },
+    });
+}
+
+fn generate_long(
+    generated_structs: &mut Vec<TokenStream>,
+    name: Ident,
+    reg_value: u16,
+    swap_words: bool,
+    scale: Option<f32>,
+    signed: bool,
+    serialize_derive: &TokenStream,
+    descriptors: &mut Vec<TokenStream>,
+    description: &str,
+    kind: TokenStream,
+) {
+    descriptors.push(quote! {
+        RegisterDescriptor {
+            address: #reg_value,
+            name: #description,
+            kind: #kind,
+            decode: |value| DecodedValue::Float(#name::from(value).0 as f32),
+        },
+    });
+
+    generated_structs.push(match (signed, scale) {
+        (false, Some(scale_value)) => quote! {
+            #[allow(unused)]
+            #[derive(Debug)]
+            #serialize_derive
+            pub struct #name(f32);
+
+            impl #name {
+                pub fn reg() -> u16 { #reg_value }
+            }
+
+            impl From<Vec<u16>> for #name {
+                fn from(value: Vec<u16>) -> Self {
+                    #name(super::register_to_u32(value, #swap_words) as f32 * #scale_value)
+                }
+            }
+
+            impl super::ToModbusRegisters for #name {
+                fn to_registers(&self) -> Result<Vec<u16>, String> {
+                    Ok(super::u32_to_register((self.0 / #scale_value) as u32, #swap_words))
+                }
+            }
+        },
+        (false, None) => quote! {
+            #[allow(unused)]
+            #[derive(Debug)]
+            #serialize_derive
+            pub struct #name(u32);
+
+            impl #name {
+                pub fn reg() -> u16 { #reg_value }
+            }
+
+            impl From<Vec<u16>> for #name {
+                fn from(value: Vec<u16>) -> Self {
+                    #name(super::register_to_u32(value, #swap_words))
+                }
+            }
+
+            impl super::ToModbusRegisters for #name {
+                fn to_registers(&self) -> Result<Vec<u16>, String> {
+                    Ok(super::u32_to_register(self.0, #swap_words))
+                }
+            }
+        },
+        (true, Some(scale_value)) => quote! {
+            #[allow(unused)]
+            #[derive(Debug)]
+            #serialize_derive
+            pub struct #name(f32);
+
+            impl #name {
+                pub fn reg() -> u16 { #reg_value }
+            }
+
+            impl From<Vec<u16>> for #name {
+                fn from(value: Vec<u16>) -> Self {
+                    #name(super::register_to_i32(value, #swap_words) as f32 * #scale_value)
+                }
+            }
+
+            impl super::ToModbusRegisters for #name {
+                fn to_registers(&self) -> Result<Vec<u16>, String> {
+                    Ok(super::i32_to_register((self.0 / #scale_value) as i32, #swap_words))
+                }
+            }
+        },
+        (true, None) => quote! {
+            #[allow(unused)]
+            #[derive(Debug)]
+            #serialize_derive
+            pub struct #name(i32);
+
+            impl #name {
+                pub fn reg() -> u16 { #reg_value }
+            }
+
+            impl From<Vec<u16>> for #name {
+                fn from(value: Vec<u16>) -> Self {
+                    #name(super::register_to_i32(value, #swap_words))
+                }
+            }
+
+            impl super::ToModbusRegisters for #name {
+                fn to_registers(&self) -> Result<Vec<u16>, String> {
+                    Ok(super::i32_to_register(self.0, #swap_words))
+                }
+            }
+        },
+    });
+}
+
 fn generate_enum(
     generated_enums: &mut Vec<TokenStream>,
     enum_name: Ident,
     reg: HashMap<String, u16>,
     reg_value: u16,
+    derive_serialize: bool,
+    descriptors: &mut Vec<TokenStream>,
+    description: &str,
+    kind: TokenStream,
+    read_only: bool,
 ) {
     let mut variants: Vec<TokenStream> = Vec::new();
     let mut match_arms: Vec<TokenStream> = Vec::new();
+    let mut encode_arms: Vec<TokenStream> = Vec::new();
+    let mut serialize_arms: Vec<TokenStream> = Vec::new();
+    let mut name_arms: Vec<TokenStream> = Vec::new();
 
     for (variant_name, value) in reg {
         let variant_ident = syn::Ident::new(
@@ -407,17 +1144,64 @@ fn generate_enum(
         match_arms.push(quote! {
             #value => #enum_name::#variant_ident,
         });
+        encode_arms.push(quote! {
+            #enum_name::#variant_ident => #value,
+        });
+        serialize_arms.push(quote! {
+            #enum_name::#variant_ident => serializer.serialize_str(stringify!(#variant_ident)),
+        });
+        name_arms.push(quote! {
+            #enum_name::#variant_ident => stringify!(#variant_ident),
+        });
     }
 
-    // Add an Unknown variant
+    // Add an Unknown variant that retains the raw register value, so an
+    // enum decoded from an unrecognized value can still round-trip on encode.
     variants.push(quote! {
-        Unknown,
+        Unknown(u16),
     });
     match_arms.push(quote! {
-        _ => #enum_name::Unknown,
+        other => #enum_name::Unknown(other),
+    });
+    encode_arms.push(quote! {
+        #enum_name::Unknown(value) => *value,
+    });
+    serialize_arms.push(quote! {
+        #enum_name::Unknown(value) => serializer.serialize_u16(*value),
+    });
+    name_arms.push(quote! {
+        #enum_name::Unknown(_) => "Unknown",
+    });
+
+    descriptors.push(quote! {
+        RegisterDescriptor {
+            address: #reg_value,
+            name: #description,
+            kind: #kind,
+            decode: |value| DecodedValue::Enum(#enum_name::from(value).register_name()),
+        },
     });
 
-    // Generate the enum and the From<Vec<u16>> implementation
+    let serialize_impl = if derive_serialize {
+        quote! {
+            impl serde::Serialize for #enum_name {
+                fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+                where
+                    S: serde::Serializer,
+                {
+                    match self {
+                        #(#serialize_arms)*
+                    }
+                }
+            }
+        }
+    } else {
+        quote! {}
+    };
+
+    let write_guard = write_guard(read_only, None, None, description);
+
+    // Generate the enum and the From<Vec<u16>>/ToModbusRegisters implementations
     generated_enums.push(quote! {
         #[derive(Debug)]
         pub enum #enum_name {
@@ -435,5 +1219,26 @@ fn generate_enum(
                 }
             }
         }
+
+        impl ToModbusRegisters for #enum_name {
+            fn to_registers(&self) -> Result<Vec<u16>, String> {
+                #write_guard
+                Ok(vec![match self {
+                    #(#encode_arms)*
+                }])
+            }
+        }
+
+        impl #enum_name {
+            pub const READ_ONLY: bool = #read_only;
+
+            pub fn register_name(&self) -> &'static str {
+                match self {
+                    #(#name_arms)*
+                }
+            }
+        }
+
+        #serialize_impl
     });
 }
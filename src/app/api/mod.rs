@@ -5,13 +5,13 @@ use axum::{Router};
 use axum::http::StatusCode;
 use axum::routing::get;
 use tokio::net::TcpListener;
-use tokio::signal;
 use utoipa::OpenApi;
 use utoipa_swagger_ui::SwaggerUi;
 use thermav_lib::config::HttpConfig;
+use thermav_lib::shutdown::Shutdown;
 use crate::api::error::Error;
 
-pub async fn start_service(cfg: HttpConfig) {
+pub async fn start_service(cfg: HttpConfig, shutdown: Shutdown) {
     #[derive(OpenApi)]
     #[openapi(
         paths(health),
@@ -35,34 +35,16 @@ pub async fn start_service(cfg: HttpConfig) {
 
     log::info!(target: "api", "Listening on http://{}", addr);
 
-    axum::serve(listener, app).with_graceful_shutdown(shutdown_signal()).await.unwrap_or_else(|e| {
-        log::error!(target: "api", "Unable to start server: {}", e);
-        std::process::exit(1);
-    });
-}
-
-async fn shutdown_signal() {
-    let ctrl_c = async {
-        signal::ctrl_c().await.unwrap_or_else(|e| {
-            log::error!(target: "http-server", "failed to install Ctrl+C handler: {}", e);
+    // Shares `shutdown`'s signal instead of registering its own Ctrl-C/SIGTERM
+    // handler, so the server and every poll worker stop at the same instant.
+    let mut shutdown_rx = shutdown.listener();
+    axum::serve(listener, app)
+        .with_graceful_shutdown(async move { shutdown_rx.recv().await })
+        .await
+        .unwrap_or_else(|e| {
+            log::error!(target: "api", "Unable to start server: {}", e);
             std::process::exit(1);
         });
-    };
-
-    let terminate = async {
-        signal::unix::signal(signal::unix::SignalKind::terminate())
-            .unwrap_or_else(|e| {
-                log::error!(target: "http-server", "failed to install terminate signal handler: {}", e);
-                std::process::exit(1);
-            })
-            .recv()
-            .await;
-    };
-
-    tokio::select! {
-        _ = ctrl_c => {},
-        _ = terminate => {},
-    }
 }
 
 
@@ -1,8 +1,10 @@
+use crate::api::error::Error;
 use crate::api::not_found;
 use axum::extract::{Path, State};
 use axum::http::StatusCode;
 use axum::response::IntoResponse;
 use axum::{routing, Json, Router};
+use serde::Deserialize;
 use utoipa::OpenApi;
 
 const API_VERSION: &str = "v1";
@@ -11,7 +13,8 @@ const API_VERSION: &str = "v1";
 #[openapi(
     paths(
         get_coil,
-        post_coil
+        post_coil,
+        post_holding
     )
 )]
 pub(super) struct ThermavApi;
@@ -28,10 +31,24 @@ pub fn create_router(hvac: thermav_lib::ThermaV) -> Router {
                 "/coils/:name",
                 routing::post(post_coil),
             )
+            .route(
+                "/holding/:name",
+                routing::post(post_holding),
+            )
             .with_state(hvac),
     )
 }
 
+#[derive(Deserialize, utoipa::ToSchema)]
+struct CoilValue {
+    value: bool,
+}
+
+#[derive(Deserialize, utoipa::ToSchema)]
+struct HoldingValue {
+    value: u16,
+}
+
 #[utoipa::path(get, path = "/v1/coils/{name}",
     responses(
         (status = OK, body = str),
@@ -50,6 +67,7 @@ async fn get_coil(
 }
 
 #[utoipa::path(post, path = "/v1/coils/{name}",
+    request_body(content = inline(CoilValue)),
     responses(
         (status = OK, body = str),
         (status = NOT_FOUND, description = "Register not found")
@@ -60,7 +78,82 @@ async fn get_coil(
 async fn post_coil(
     Path(name): Path<String>,
     State(hvac): State<thermav_lib::ThermaV>,
+    Json(body): Json<CoilValue>,
 ) -> impl IntoResponse {
+    match hvac.set_coil_by_name(&name, body.value).await {
+        Ok(()) => StatusCode::OK.into_response(),
+        Err(err) if hvac.coil_address(&name).is_none() => {
+            not_found(err, Some(format!("no such coil: {name}"))).into_response()
+        }
+        Err(err) => Error {
+            status_code: StatusCode::BAD_GATEWAY,
+            message: err,
+            details: None,
+        }
+        .into_response(),
+    }
+}
 
-    not_found("Register not found".into(), None).into_response()
+/// Decodes `value` with the register's configured scale and checks it against
+/// `min`/`max`, returning a `400 Bad Request` `Error` when out of range. A
+/// register with no configured mapping (still served from the built-in
+/// defaults) or no configured bounds passes unchecked, as does a `U32`/`S32`/
+/// `F32` register: this endpoint only ever writes a single raw word (see
+/// `set_register_by_name`), so a wider register can't be decoded from it.
+fn validate_holding_range(hvac: &thermav_lib::ThermaV, name: &str, value: u16) -> Option<Error> {
+    let reg = hvac.register_config_by_name(name)?;
+    if reg.word_count() != 1 {
+        return None;
+    }
+    let decoded = reg.decode(&[value]);
+    if let Some(min) = reg.min {
+        if decoded < min {
+            return Some(Error {
+                status_code: StatusCode::BAD_REQUEST,
+                message: format!("value {decoded} below minimum {min} for '{name}'"),
+                details: None,
+            });
+        }
+    }
+    if let Some(max) = reg.max {
+        if decoded > max {
+            return Some(Error {
+                status_code: StatusCode::BAD_REQUEST,
+                message: format!("value {decoded} above maximum {max} for '{name}'"),
+                details: None,
+            });
+        }
+    }
+    None
+}
+
+#[utoipa::path(post, path = "/v1/holding/{name}",
+    request_body(content = inline(HoldingValue)),
+    responses(
+        (status = OK, body = str),
+        (status = NOT_FOUND, description = "Register not found")
+    ),
+    params(
+            ("name" = String, Path, description = "Register name"),
+    ))]
+async fn post_holding(
+    Path(name): Path<String>,
+    State(hvac): State<thermav_lib::ThermaV>,
+    Json(body): Json<HoldingValue>,
+) -> impl IntoResponse {
+    if let Some(err) = validate_holding_range(&hvac, &name, body.value) {
+        return err.into_response();
+    }
+    match hvac.set_register_by_name(&name, body.value).await {
+        Ok(()) => StatusCode::OK.into_response(),
+        Err(err) if hvac.holding_address(&name).is_none() => {
+            not_found(err, Some(format!("no such holding register: {name}"))).into_response()
+        }
+        Err(err) => Error {
+            status_code: StatusCode::BAD_GATEWAY,
+            message: err,
+            details: None,
+        }
+        .into_response(),
+    }
 }
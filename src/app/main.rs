@@ -1,10 +1,9 @@
 use std::ops::Deref;
-use std::sync::atomic::AtomicBool;
-use std::sync::Arc;
 use thermav_lib::config;
 use thermav_lib::hass::start_hass_mqtt_bridge_task;
 #[cfg(feature = "mqtt")]
 use thermav_lib::mqtt;
+use thermav_lib::shutdown::Shutdown;
 
 use crate::api::start_service;
 use thermav_lib::ThermaV;
@@ -19,30 +18,54 @@ async fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
     pretty_env_logger::init();
     let cfg = config::read_config();
-    let interrupted = Arc::new(AtomicBool::new(false));
+    let shutdown = Shutdown::new();
+    // Not yet migrated off the polled-bool pattern.
+    let legacy_shutdown = shutdown.legacy_flag();
 
-    let modbus = ThermaV::new(cfg.therma, interrupted.clone())
+    #[allow(unused_variables)]
+    let (modbus, modbus_rx) = ThermaV::new(cfg.therma, cfg.registers.clone(), shutdown.clone())
         .await
         .unwrap_or_else(|e| {
             log::error!(target: "main", "Unable to initialize modbus: {}", e);
             std::process::exit(1);
         });
 
+    if let Some(control_cfg) = cfg.control.clone() {
+        modbus.spawn_weather_compensation(control_cfg, shutdown.clone()).await;
+    }
+
     #[cfg(feature = "mqtt")]
     {
         #[allow(unused_variables)]
-        let mqtt_client = mqtt::Client::new(&cfg.mqtt, interrupted.clone());
+        let (mqtt_client, mqtt_rx) = mqtt::Client::new(&cfg.mqtt, legacy_shutdown.clone());
 
         #[cfg(not(feature = "hass"))]
-        mqtt::modbus_to_mqtt::start_publish_task(mqtt_client, modbus.deref(), interrupted.clone());
+        mqtt::modbus_to_mqtt::start_publish_task(
+            mqtt_client,
+            modbus.deref(),
+            cfg.registers.clone(),
+            legacy_shutdown.clone(),
+        );
 
         #[cfg(feature = "hass")]
-        start_hass_mqtt_bridge_task(mqtt_client, modbus.deref(), interrupted.clone());
+        start_hass_mqtt_bridge_task(
+            modbus.clone(),
+            mqtt_client,
+            modbus_rx,
+            mqtt_rx,
+            cfg.registers.clone(),
+            legacy_shutdown.clone(),
+        );
     }
 
-    start_service(cfg.http).await;
+    // A single task owns waiting for Ctrl-C/SIGTERM, so the HTTP server and
+    // every poll worker observe shutdown through the same signal instead of
+    // each registering its own handler.
+    let signal_shutdown = shutdown.clone();
+    tokio::spawn(async move { signal_shutdown.listen_for_signal().await });
 
-    interrupted.store(true, std::sync::atomic::Ordering::Relaxed);
+    start_service(cfg.http, shutdown.clone()).await;
+    shutdown.wait_for_tasks().await;
 
     Ok(())
 }
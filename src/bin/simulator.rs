@@ -0,0 +1,28 @@
+//! Stands up a Modbus RTU simulator backing the same register map this crate
+//! reads, so the polling loop in `thermav`'s `main()` can be exercised
+//! without a real Therma V on `/dev/ttyUSB0`. Point `therma.tty_path` at one
+//! end of a PTY pair (e.g. via `socat PTY,link=/tmp/therma-sim PTY,link=/tmp/therma-client`)
+//! and this binary at the other.
+use std::time::Duration;
+use thermav_lib::sim::{SimulatedRegisters, Simulator};
+use tokio_serial::SerialPortBuilderExt;
+
+#[tokio::main]
+async fn main() -> Result<(), Box<dyn std::error::Error>> {
+    if std::env::var("RUST_LOG").is_err() {
+        std::env::set_var("RUST_LOG", "info")
+    }
+    pretty_env_logger::init();
+
+    let tty_path = std::env::var("SIM_TTY_PATH").unwrap_or_else(|_| "/tmp/therma-sim".into());
+    let port = tokio_serial::new(&tty_path, thermav_lib::config::DEFAULT_BAUD_RATE).open_native_async()?;
+
+    let registers = SimulatedRegisters::seeded();
+    registers.spawn_drift(Duration::from_secs(5));
+
+    log::info!(target: "sim", "Serving simulated Therma V registers on {}", tty_path);
+    tokio_modbus::server::rtu::Server::new(port)
+        .serve_forever(Simulator::new(registers))
+        .await;
+    Ok(())
+}
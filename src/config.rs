@@ -1,5 +1,6 @@
 use config::{Config, Environment, File};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::env;
 
 pub const DEFAULT_BAUD_RATE: u32 = 9600;
@@ -29,12 +30,245 @@ pub struct ThermaConfig {
     pub timeout_ms: u64,
 }
 
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash)]
+#[serde(rename_all = "snake_case")]
+pub enum RegisterKind {
+    Coil,
+    Discrete,
+    Holding,
+    Input,
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum RegisterDataType {
+    U16,
+    S16,
+    U32,
+    S32,
+    F32,
+}
+
+/// One user-configurable Modbus-register-to-Home-Assistant-sensor mapping.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct RegisterMap {
+    pub kind: RegisterKind,
+    pub address: u16,
+    pub name: String,
+    pub data_type: RegisterDataType,
+    #[serde(default)]
+    pub scale: Option<f64>,
+    #[serde(default)]
+    pub swap_words: bool,
+    #[serde(default)]
+    pub unit_of_measurement: Option<String>,
+    #[serde(default)]
+    pub device_class: Option<String>,
+    #[serde(default)]
+    pub icon: Option<String>,
+    /// Whether this register accepts writes (coil/holding only) over MQTT and HTTP.
+    #[serde(default)]
+    pub writable: bool,
+    /// Poll cadence, e.g. `"1s"`, `"3s"`, `"1m"`. Unset means "poll every cycle".
+    #[serde(default)]
+    pub period: Option<String>,
+    /// Lower bound in engineering units, for registers that back a climate/water_heater setpoint.
+    #[serde(default)]
+    pub min: Option<f64>,
+    /// Upper bound in engineering units, for registers that back a climate/water_heater setpoint.
+    #[serde(default)]
+    pub max: Option<f64>,
+    /// Step size in engineering units, for registers that back a climate/water_heater setpoint.
+    #[serde(default)]
+    pub step: Option<f64>,
+    /// Whether this register is polled/published at all. Lets a site disable a
+    /// register without deleting its entry, so it can be turned back on later
+    /// without recompiling. Defaults to `true`.
+    #[serde(default = "default_enabled")]
+    pub enabled: bool,
+}
+
+fn default_enabled() -> bool {
+    true
+}
+
+impl RegisterMap {
+    /// Parses `period` (`"<n>s"`/`"<n>m"`/`"<n>h"`) into a `Duration`, falling back
+    /// to a conservative 5s default when the field is present but malformed.
+    pub fn poll_interval(&self) -> Option<std::time::Duration> {
+        let period = self.period.as_deref()?;
+        let (digits, unit) = period.split_at(period.len().saturating_sub(1));
+        let Ok(value) = digits.parse::<u64>() else {
+            return Some(std::time::Duration::from_secs(5));
+        };
+        Some(match unit {
+            "s" => std::time::Duration::from_secs(value),
+            "m" => std::time::Duration::from_secs(value * 60),
+            "h" => std::time::Duration::from_secs(value * 3600),
+            _ => std::time::Duration::from_secs(5),
+        })
+    }
+}
+
+/// Tracks per-register next-due times so registers configured with a
+/// `period` are polled/republished on their own cadence instead of on every
+/// scan cycle or frame. Registers with no configured period are always due,
+/// preserving the default fixed-cadence behavior. Shared by the Modbus
+/// polling loop (`ThermaV::new`) and the MQTT publish task
+/// (`modbus_to_mqtt::start_publish_task`), which otherwise track the exact
+/// same due-time bookkeeping.
+pub struct DueScheduler {
+    periods: HashMap<(RegisterKind, u16), std::time::Duration>,
+    next_due: HashMap<(RegisterKind, u16), std::time::Instant>,
+}
+
+impl DueScheduler {
+    pub fn new(registers: &[RegisterMap]) -> Self {
+        let periods = registers
+            .iter()
+            .filter_map(|reg| reg.poll_interval().map(|period| ((reg.kind, reg.address), period)))
+            .collect();
+        Self {
+            periods,
+            next_due: HashMap::new(),
+        }
+    }
+
+    /// Returns whether `addr` is due, advancing its next-due time if so.
+    pub fn due(&mut self, kind: RegisterKind, addr: u16) -> bool {
+        let Some(&period) = self.periods.get(&(kind, addr)) else {
+            return true;
+        };
+        let now = std::time::Instant::now();
+        let ready = self
+            .next_due
+            .get(&(kind, addr))
+            .map(|due| now >= *due)
+            .unwrap_or(true);
+        if ready {
+            self.next_due.insert((kind, addr), now + period);
+        }
+        ready
+    }
+}
+
+impl RegisterMap {
+    /// Number of consecutive 16-bit Modbus words this register spans.
+    pub fn word_count(&self) -> usize {
+        match self.data_type {
+            RegisterDataType::U16 | RegisterDataType::S16 => 1,
+            RegisterDataType::U32 | RegisterDataType::S32 | RegisterDataType::F32 => 2,
+        }
+    }
+
+    /// Decodes the raw register words into a scaled, signedness-aware value,
+    /// honoring `swap_words` for 32-bit quantities. `F32` words are reassembled
+    /// bit-for-bit (not cast) before the IEEE-754 reinterpretation.
+    pub fn decode(&self, words: &[u16]) -> f64 {
+        let scale = self.scale.unwrap_or(1.0);
+        if self.data_type == RegisterDataType::F32 {
+            return f32::from_bits(combine_words(words, self.swap_words)) as f64 * scale;
+        }
+        let raw: i64 = match self.data_type {
+            RegisterDataType::U16 => words[0] as i64,
+            RegisterDataType::S16 => words[0] as i16 as i64,
+            RegisterDataType::U32 => combine_words(words, self.swap_words) as i64,
+            RegisterDataType::S32 => combine_words(words, self.swap_words) as i32 as i64,
+            RegisterDataType::F32 => unreachable!(),
+        };
+        raw as f64 * scale
+    }
+}
+
+/// A decoded register value paired with its configured unit, so callers
+/// don't have to remember out-of-band that e.g. water temperatures are
+/// tenths of a degree.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Measurement {
+    pub value: f32,
+    pub unit: Option<String>,
+}
+
+impl RegisterMap {
+    /// Like `decode`, but attaches `unit_of_measurement` to the result.
+    pub fn decode_measurement(&self, words: &[u16]) -> Measurement {
+        Measurement {
+            value: self.decode(words) as f32,
+            unit: self.unit_of_measurement.clone(),
+        }
+    }
+}
+
+fn combine_words(words: &[u16], swap_words: bool) -> u32 {
+    let (high, low) = if swap_words {
+        (words[1], words[0])
+    } else {
+        (words[0], words[1])
+    };
+    ((high as u32) << 16) | low as u32
+}
+
+/// Finds the configured mapping for a given register kind and address, if any.
+pub fn find_register(
+    registers: &[RegisterMap],
+    kind: RegisterKind,
+    address: u16,
+) -> Option<&RegisterMap> {
+    registers
+        .iter()
+        .find(|reg| reg.kind == kind && reg.address == address)
+}
+
+/// One outdoor-temp/flow-temp breakpoint of a `HeatingCurve`.
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct CurvePointConfig {
+    pub outdoor_temp: f64,
+    pub flow_temp: f64,
+}
+
+/// PID gains for closing the loop on measured vs. target flow temperature.
+/// Omitting this from `ControlConfig` runs feed-forward only (curve output
+/// written directly).
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+pub struct PidConfig {
+    pub kp: f64,
+    pub ki: f64,
+    pub kd: f64,
+}
+
+/// Weather-compensation heating-curve control, disabled by default so
+/// enabling it is an explicit opt-in per deployment.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+pub struct ControlConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    /// Name of the input register read as the outdoor temperature.
+    pub outdoor_register: String,
+    /// Name of the input register read as the measured flow temperature
+    /// (only consulted when `pid` is set).
+    pub measured_flow_register: String,
+    /// Name of the holding register the computed setpoint is written to.
+    pub target_register: String,
+    /// IIR time constant, in seconds, for smoothing `outdoor_register`.
+    pub tau_secs: f64,
+    /// Heating-curve breakpoints, sorted by ascending `outdoor_temp`.
+    pub curve_points: Vec<CurvePointConfig>,
+    pub min_flow_temp: f64,
+    pub max_flow_temp: f64,
+    #[serde(default)]
+    pub pid: Option<PidConfig>,
+}
+
 #[derive(Clone, Serialize, Deserialize)]
 #[allow(unused)]
 pub struct AppConfig {
     pub http: HttpConfig,
     pub mqtt: MqttConfig,
     pub therma: ThermaConfig,
+    #[serde(default)]
+    pub registers: Vec<RegisterMap>,
+    #[serde(default)]
+    pub control: Option<ControlConfig>,
 }
 
 pub fn read_config() -> AppConfig {
@@ -49,10 +283,14 @@ pub fn read_config() -> AppConfig {
             std::process::exit(1);
         });
 
-    s.try_deserialize().unwrap_or_else(|e| {
+    let mut cfg: AppConfig = s.try_deserialize().unwrap_or_else(|e| {
         log::error!(target: "config", "Error deserializing config file: {}", e);
         std::process::exit(1);
-    })
+    });
+    // Disabled registers are dropped here so every consumer (poll scheduler,
+    // MQTT publisher, HTTP API, HA discovery) sees only the active set.
+    cfg.registers.retain(|reg| reg.enabled);
+    cfg
 }
 
 #[test]
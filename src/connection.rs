@@ -0,0 +1,121 @@
+use crate::config::{ThermaConfig, DEFAULT_BAUD_RATE};
+use crate::shutdown::Shutdown;
+use log::{error, info, warn};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex as StdMutex};
+use std::time::Duration;
+use tokio::sync::Mutex;
+use tokio_modbus::client::{self, rtu};
+use tokio_modbus::Slave;
+use tokio_serial::SerialStream;
+
+/// Consecutive supervisor checks (one every `POLL_INTERVAL`) that find the
+/// link down (see `ThermaV::is_connected`) before the supervisor tears down
+/// and rebuilds the serial context.
+const FAILURE_THRESHOLD: u32 = 3;
+const POLL_INTERVAL: Duration = Duration::from_millis(500);
+const INITIAL_BACKOFF: Duration = Duration::from_secs(1);
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Health of the Modbus transport, suitable for publishing as a diagnostic topic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinkState {
+    Connected,
+    Reconnecting,
+    Down,
+}
+
+/// Watches `ThermaV`'s connected flag and, after `FAILURE_THRESHOLD`
+/// consecutive checks that find it down, tears down and rebuilds the `SerialStream`/
+/// `rtu::attach_slave` context with exponential backoff between reopen
+/// attempts. Modeled on modbus-mqtt's dedicated connection module rather than
+/// leaving a single unwrapped `Context` handle in the hot read/write path.
+#[derive(Clone, Debug)]
+pub struct ConnectionSupervisor {
+    state: Arc<StdMutex<LinkState>>,
+}
+
+impl ConnectionSupervisor {
+    /// A supervisor with no background task, used when the `io` feature is
+    /// disabled or no context was opened (simulated/unplugged runs).
+    pub fn idle() -> Self {
+        Self {
+            state: Arc::new(StdMutex::new(LinkState::Connected)),
+        }
+    }
+
+    /// Spawns the background watcher, registers it with `shutdown` so the
+    /// process waits for the serial context to settle before exiting, and
+    /// returns the handle tracking its state.
+    pub async fn spawn(
+        cfg: ThermaConfig,
+        ctx: Arc<Mutex<client::Context>>,
+        connected: Arc<AtomicBool>,
+        shutdown: Shutdown,
+    ) -> Self {
+        let supervisor = Self {
+            state: Arc::new(StdMutex::new(LinkState::Connected)),
+        };
+        let state = supervisor.state.clone();
+        let mut shutdown_rx = shutdown.listener();
+        let handle = tokio::spawn(async move {
+            let mut consecutive_failures = 0u32;
+            loop {
+                tokio::select! {
+                    _ = tokio::time::sleep(POLL_INTERVAL) => {},
+                    _ = shutdown_rx.recv() => break,
+                }
+                if connected.load(Ordering::Relaxed) {
+                    consecutive_failures = 0;
+                    *state.lock().unwrap() = LinkState::Connected;
+                    continue;
+                }
+                consecutive_failures += 1;
+                if consecutive_failures < FAILURE_THRESHOLD {
+                    continue;
+                }
+                *state.lock().unwrap() = LinkState::Reconnecting;
+                warn!(target: "modbus:connection", "{} consecutive failures, rebuilding serial context", consecutive_failures);
+                let mut backoff = INITIAL_BACKOFF;
+                loop {
+                    match reopen(&cfg) {
+                        Ok(new_ctx) => {
+                            *ctx.lock().await = new_ctx;
+                            connected.store(true, Ordering::Relaxed);
+                            consecutive_failures = 0;
+                            *state.lock().unwrap() = LinkState::Connected;
+                            info!(target: "modbus:connection", "serial context rebuilt, link restored");
+                            break;
+                        }
+                        Err(err) => {
+                            *state.lock().unwrap() = LinkState::Down;
+                            error!(target: "modbus:connection", "reopen failed: {}, retrying in {:?}", err, backoff);
+                            tokio::select! {
+                                _ = tokio::time::sleep(backoff) => {},
+                                _ = shutdown_rx.recv() => return,
+                            }
+                            backoff = (backoff * 2).min(MAX_BACKOFF);
+                        }
+                    }
+                }
+            }
+            // Only this task's handle on `ctx` is dropped here; the
+            // `SerialStream` closes once `ThermaV` and its clones drop theirs.
+        });
+        shutdown.register(handle).await;
+        supervisor
+    }
+
+    pub fn state(&self) -> LinkState {
+        *self.state.lock().unwrap()
+    }
+}
+
+/// Builds a fresh serial context, used both for the initial connect in
+/// `ThermaV::new` and for every rebuild attempt below.
+pub(crate) fn reopen(cfg: &ThermaConfig) -> std::io::Result<client::Context> {
+    let builder = tokio_serial::new(cfg.tty_path.clone(), DEFAULT_BAUD_RATE)
+        .timeout(Duration::from_millis(cfg.timeout_ms));
+    let port = SerialStream::open(&builder)?;
+    Ok(rtu::attach_slave(port, Slave(cfg.slave_id)))
+}
@@ -0,0 +1,183 @@
+//! Weather-compensation control building blocks: an IIR low-pass for noisy
+//! sensor readings, a piecewise-linear outdoor-to-flow heating curve, and a
+//! discrete PID with anti-windup for closing the loop on measured flow temp.
+use crate::config::ControlConfig;
+
+/// First-order IIR low-pass: `y[n] = y[n-1] + alpha*(x[n] - y[n-1])`, with
+/// `alpha = dt/(tau+dt)` recomputed each sample so a variable poll period
+/// doesn't skew the effective time constant.
+#[derive(Debug, Clone, Copy)]
+pub struct IirFilter {
+    tau_secs: f64,
+    state: Option<f64>,
+}
+
+impl IirFilter {
+    pub fn new(tau_secs: f64) -> Self {
+        Self {
+            tau_secs,
+            state: None,
+        }
+    }
+
+    /// Feeds in one sample and returns the smoothed value. The first sample
+    /// seeds the filter directly so it doesn't start at zero.
+    pub fn update(&mut self, sample: f64, dt_secs: f64) -> f64 {
+        let y = match self.state {
+            None => sample,
+            Some(prev) => {
+                let alpha = dt_secs / (self.tau_secs + dt_secs);
+                prev + alpha * (sample - prev)
+            }
+        };
+        self.state = Some(y);
+        y
+    }
+}
+
+/// A breakpoint in a heating curve: outdoor temperature mapped to flow
+/// setpoint, both in the register's engineering units.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct CurvePoint {
+    pub outdoor_temp: f64,
+    pub flow_temp: f64,
+}
+
+/// Piecewise-linear outdoor-to-flow-setpoint mapping, clamped to the curve's
+/// own endpoints outside its configured range.
+#[derive(Debug, Clone)]
+pub struct HeatingCurve {
+    points: Vec<CurvePoint>,
+}
+
+impl HeatingCurve {
+    /// Points must be sorted by ascending `outdoor_temp`; at least one point
+    /// is required. Returns `None` for an empty `points`, since `flow_setpoint`
+    /// indexes the first/last entry unconditionally.
+    pub fn new(points: Vec<CurvePoint>) -> Option<Self> {
+        if points.is_empty() {
+            return None;
+        }
+        Some(Self { points })
+    }
+
+    pub fn flow_setpoint(&self, outdoor_temp: f64) -> f64 {
+        let points = &self.points;
+        if outdoor_temp <= points[0].outdoor_temp {
+            return points[0].flow_temp;
+        }
+        let last = points.len() - 1;
+        if outdoor_temp >= points[last].outdoor_temp {
+            return points[last].flow_temp;
+        }
+        for window in points.windows(2) {
+            let (lo, hi) = (window[0], window[1]);
+            if outdoor_temp >= lo.outdoor_temp && outdoor_temp <= hi.outdoor_temp {
+                let span = hi.outdoor_temp - lo.outdoor_temp;
+                if span == 0.0 {
+                    return lo.flow_temp;
+                }
+                let fraction = (outdoor_temp - lo.outdoor_temp) / span;
+                return lo.flow_temp + fraction * (hi.flow_temp - lo.flow_temp);
+            }
+        }
+        points[last].flow_temp
+    }
+}
+
+/// Discrete PID with anti-windup: the integral term is frozen while the
+/// output is clamped, so it can't accumulate past what the actuator could
+/// ever use.
+#[derive(Debug, Clone, Copy)]
+pub struct Pid {
+    kp: f64,
+    ki: f64,
+    kd: f64,
+    min_out: f64,
+    max_out: f64,
+    integral: f64,
+    prev_error: Option<f64>,
+}
+
+impl Pid {
+    pub fn new(kp: f64, ki: f64, kd: f64, min_out: f64, max_out: f64) -> Self {
+        Self {
+            kp,
+            ki,
+            kd,
+            min_out,
+            max_out,
+            integral: 0.0,
+            prev_error: None,
+        }
+    }
+
+    pub fn update(&mut self, setpoint: f64, measured: f64, dt_secs: f64) -> f64 {
+        let error = setpoint - measured;
+        let derivative = match self.prev_error {
+            Some(prev) if dt_secs > 0.0 => (error - prev) / dt_secs,
+            _ => 0.0,
+        };
+        self.prev_error = Some(error);
+
+        let unclamped_integral = self.integral + error * dt_secs;
+        let candidate = self.kp * error + self.ki * unclamped_integral + self.kd * derivative;
+        let clamped = candidate.clamp(self.min_out, self.max_out);
+
+        // Anti-windup: only commit the new integral term if doing so didn't
+        // require clamping the output.
+        if clamped == candidate {
+            self.integral = unclamped_integral;
+        }
+        clamped
+    }
+}
+
+/// Ties an `IirFilter`, `HeatingCurve`, and optional `Pid` together from a
+/// `ControlConfig`, ready to drive one polling loop.
+pub struct WeatherCompensation {
+    outdoor_filter: IirFilter,
+    curve: HeatingCurve,
+    pid: Option<Pid>,
+    min_flow_temp: f64,
+    max_flow_temp: f64,
+}
+
+impl WeatherCompensation {
+    /// Returns `None` if `cfg.curve_points` is empty, since `HeatingCurve`
+    /// requires at least one breakpoint.
+    pub fn from_config(cfg: &ControlConfig) -> Option<Self> {
+        let points = cfg
+            .curve_points
+            .iter()
+            .map(|p| CurvePoint {
+                outdoor_temp: p.outdoor_temp,
+                flow_temp: p.flow_temp,
+            })
+            .collect();
+        Some(Self {
+            outdoor_filter: IirFilter::new(cfg.tau_secs),
+            curve: HeatingCurve::new(points)?,
+            pid: cfg
+                .pid
+                .as_ref()
+                .map(|p| Pid::new(p.kp, p.ki, p.kd, cfg.min_flow_temp, cfg.max_flow_temp)),
+            min_flow_temp: cfg.min_flow_temp,
+            max_flow_temp: cfg.max_flow_temp,
+        })
+    }
+
+    /// Smooths `outdoor_temp`, maps it to a flow setpoint via the heating
+    /// curve, and — if a PID is configured — closes the loop against
+    /// `measured_flow_temp` to produce the value to write to the flow
+    /// register. Without a PID, the setpoint itself is returned (feed-forward
+    /// only), clamped to the configured range.
+    pub fn step(&mut self, outdoor_temp: f64, measured_flow_temp: f64, dt_secs: f64) -> f64 {
+        let smoothed_outdoor = self.outdoor_filter.update(outdoor_temp, dt_secs);
+        let setpoint = self.curve.flow_setpoint(smoothed_outdoor);
+        match &mut self.pid {
+            Some(pid) => pid.update(setpoint, measured_flow_temp, dt_secs),
+            None => setpoint.clamp(self.min_flow_temp, self.max_flow_temp),
+        }
+    }
+}
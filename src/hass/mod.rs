@@ -1,7 +1,9 @@
+use crate::config::{find_register, RegisterKind, RegisterMap};
 use crate::registers::{coil, holding, ModbusRegister};
 use crate::{mqtt, rwlock_read_guard, rwlock_write_guard, Register, SignalListener, ThermaV};
 use config::Map;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
 use tokio::sync::mpsc::Receiver;
@@ -71,6 +73,12 @@ pub struct Component {
     pub temperature_command_topic: Option<String>,
     #[serde(rename = "curr_temp_t", skip_serializing_if = "Option::is_none")]
     pub current_temperature_topic: Option<String>,
+    #[serde(rename = "min_temp", skip_serializing_if = "Option::is_none")]
+    pub min_temp: Option<f64>,
+    #[serde(rename = "max_temp", skip_serializing_if = "Option::is_none")]
+    pub max_temp: Option<f64>,
+    #[serde(rename = "temp_step", skip_serializing_if = "Option::is_none")]
+    pub temp_step: Option<f64>,
 
     #[serde(rename = "ops", skip_serializing_if = "Option::is_none")]
     pub options: Option<Vec<String>>,
@@ -119,12 +127,63 @@ impl Component {
         self
     }
 
+    /// Builds a `climate` platform component (mode + target/current temperature)
+    /// for the main space heating/cooling circuit.
+    pub fn climate(mut self, modes: Vec<&str>) -> Self {
+        self.platform = "climate".to_string();
+        self.mode_state_topic = Some(format!("{}/mode", self.state_topic.clone()));
+        self.mode_command_topic = Some(format!("{}/mode/set", self.state_topic.clone()));
+        self.temperature_state_topic = Some(format!("{}/temperature", self.state_topic.clone()));
+        self.temperature_command_topic =
+            Some(format!("{}/temperature/set", self.state_topic.clone()));
+        self.current_temperature_topic =
+            Some(format!("{}/current_temperature", self.state_topic.clone()));
+        self.modes = Some(modes.iter().map(|s| s.to_string()).collect());
+        self
+    }
+
+    /// Binds the temperature setpoint bounds (engineering units) of a composite
+    /// `water_heater`/`climate` component to a register's configured `min`/`max`/`step`.
+    pub fn with_setpoint_bounds(mut self, reg: &RegisterMap) -> Self {
+        self.min_temp = reg.min;
+        self.max_temp = reg.max;
+        self.temp_step = reg.step;
+        self
+    }
+
     pub fn select(mut self, options: Vec<&str>) -> Self {
         self.platform = "select".to_string();
         self.options = Some(options.iter().map(|s| s.to_string()).collect());
         self.command_topic = Some(format!("{}/mode", self.state_topic.clone()));
         self
     }
+
+    /// Builds a sensor or binary_sensor component from a user-configured `RegisterMap`.
+    pub fn from_register_map(device_name: &str, device_id: &str, reg: &RegisterMap) -> Self {
+        let id = slug(&reg.name);
+        let icon = reg.icon.clone().unwrap_or_else(|| "mdi:gauge".to_string());
+        let component = Self::new(&reg.name, device_name, device_id, &id, &icon);
+        let mut component = match reg.kind {
+            RegisterKind::Coil | RegisterKind::Discrete => component.binary_sensor(),
+            RegisterKind::Holding | RegisterKind::Input => Self {
+                platform: "sensor".to_string(),
+                device_class: reg.device_class.clone(),
+                unit_of_measurement: reg.unit_of_measurement.clone(),
+                ..component
+            },
+        };
+        if reg.writable {
+            component.command_topic = Some(format!("{}/set", component.state_topic));
+        }
+        component
+    }
+}
+
+fn slug(name: &str) -> String {
+    name.chars()
+        .map(|c| if c.is_alphanumeric() { c } else { '_' })
+        .collect::<String>()
+        .to_lowercase()
 }
 
 #[derive(Clone, Serialize)]
@@ -181,7 +240,10 @@ pub trait DeviceProperties {
     fn model(&self) -> String;
 }
 
-fn create_discovery_message(device_properties: &impl DeviceProperties) -> Discovery {
+fn create_discovery_message(
+    device_properties: &impl DeviceProperties,
+    registers: &[RegisterMap],
+) -> Discovery {
     let device_config: DeviceConfig = DeviceConfig {
         id: device_properties.id(),
         name: device_properties.name(),
@@ -190,85 +252,137 @@ fn create_discovery_message(device_properties: &impl DeviceProperties) -> Discov
     };
 
     let mut map = Map::<String, Component>::new();
-    map.insert(
-        map.len().to_string(),
-        Component::new(
-            "Inlet Temperature",
-            device_properties.base_topic().as_str(),
-            device_config.id.as_str(),
-            "water_inlet_temperature",
-            "mdi:water-thermometer",
-        )
-        .temperature_sensor(),
-    );
 
-    map.insert(
-        map.len().to_string(),
-        Component::new(
-            "Outlet Temperature",
-            device_properties.base_topic().as_str(),
-            device_config.id.as_str(),
-            "water_outlet_temperature",
-            "mdi:water-thermometer",
-        )
-        .temperature_sensor(),
-    );
+    // Looked up ahead of the per-register loop so the matching registers can
+    // be excluded from it below: the DHW/climate composite entities already
+    // surface these registers' value/bounds, so mirroring them as plain
+    // sensors too would just be duplicate entities in HA.
+    let dhw_target_temp = registers
+        .iter()
+        .find(|reg| reg.name.eq_ignore_ascii_case("DHW Target Temperature"));
+    let climate_target_temp = registers
+        .iter()
+        .find(|reg| reg.name.eq_ignore_ascii_case("Target Temp Heating Cooling Circuit1"));
+
+    // The config-driven register map and the hardcoded built-in sensors are
+    // alternative sources for the plain, per-register sensors: either the
+    // operator configured `AppConfig.registers` and we publish exactly those,
+    // or we fall back to the default sensor set below. Either way, the
+    // composite `water_heater`/`climate`/`select` entities are always
+    // published afterwards, since `composite_commands` depends on them being
+    // present in the discovery map regardless of which sensor source is used.
+    if !registers.is_empty() {
+        for reg in registers {
+            if dhw_target_temp.is_some_and(|dhw_reg| std::ptr::eq(dhw_reg, reg))
+                || climate_target_temp.is_some_and(|climate_reg| std::ptr::eq(climate_reg, reg))
+            {
+                continue;
+            }
+            map.insert(
+                map.len().to_string(),
+                Component::from_register_map(
+                    device_properties.base_topic().as_str(),
+                    device_config.id.as_str(),
+                    reg,
+                ),
+            );
+        }
+    } else {
+        map.insert(
+            map.len().to_string(),
+            Component::new(
+                "Inlet Temperature",
+                device_properties.base_topic().as_str(),
+                device_config.id.as_str(),
+                "water_inlet_temperature",
+                "mdi:water-thermometer",
+            )
+            .temperature_sensor(),
+        );
+
+        map.insert(
+            map.len().to_string(),
+            Component::new(
+                "Outlet Temperature",
+                device_properties.base_topic().as_str(),
+                device_config.id.as_str(),
+                "water_outlet_temperature",
+                "mdi:water-thermometer",
+            )
+            .temperature_sensor(),
+        );
+
+        map.insert(
+            map.len().to_string(),
+            Component::new(
+                "Water Flow Status",
+                device_properties.base_topic().as_str(),
+                device_config.id.as_str(),
+                "water_flow_status",
+                "mdi:waves-arrow-right",
+            )
+            .binary_sensor(),
+        );
+        map.insert(
+            map.len().to_string(),
+            Component::new(
+                "Water Pump Status",
+                device_properties.base_topic().as_str(),
+                device_config.id.as_str(),
+                "water_pump_status",
+                "mdi:heat-pump",
+            )
+            .binary_sensor(),
+        );
+        map.insert(
+            map.len().to_string(),
+            Component::new(
+                "Compressor Status",
+                device_properties.base_topic().as_str(),
+                device_config.id.as_str(),
+                "compressor_status",
+                "mdi:arrow-collapse-all",
+            )
+            .binary_sensor(),
+        );
+        map.insert(
+            map.len().to_string(),
+            Component::new(
+                "DHW Heating Status",
+                device_properties.base_topic().as_str(),
+                device_config.id.as_str(),
+                "d_h_w_heating_status_d_h_w_thermal_on_off",
+                "mdi:water-boiler",
+            )
+            .binary_sensor(),
+        );
+    }
 
-    map.insert(
-        map.len().to_string(),
-        Component::new(
-            "Water Flow Status",
-            device_properties.base_topic().as_str(),
-            device_config.id.as_str(),
-            "water_flow_status",
-            "mdi:waves-arrow-right",
-        )
-        .binary_sensor(),
-    );
-    map.insert(
-        map.len().to_string(),
-        Component::new(
-            "Water Pump Status",
-            device_properties.base_topic().as_str(),
-            device_config.id.as_str(),
-            "water_pump_status",
-            "mdi:heat-pump",
-        )
-        .binary_sensor(),
-    );
-    map.insert(
-        map.len().to_string(),
-        Component::new(
-            "Compressor Status",
-            device_properties.base_topic().as_str(),
-            device_config.id.as_str(),
-            "compressor_status",
-            "mdi:arrow-collapse-all",
-        )
-        .binary_sensor(),
-    );
-    map.insert(
-        map.len().to_string(),
-        Component::new(
-            "DHW Heating Status",
-            device_properties.base_topic().as_str(),
-            device_config.id.as_str(),
-            "d_h_w_heating_status_d_h_w_thermal_on_off",
-            "mdi:water-boiler",
-        )
-        .binary_sensor(),
-    );
-    map.insert(
-        map.len().to_string(),
-        Component::new(
-            "DHW",
-            device_properties.base_topic().as_str(),
-            device_config.id.as_str(),
-            "dhw",
-            "mdi:water-boiler",
-        )
-        .water_heater(vec!["off", "heat_pump"]),
-    );
+    let mut dhw = Component::new(
+        "DHW",
+        device_properties.base_topic().as_str(),
+        device_config.id.as_str(),
+        "dhw",
+        "mdi:water-boiler",
+    )
+    .water_heater(vec!["off", "heat_pump"]);
+    if let Some(target_temp) = dhw_target_temp {
+        dhw = dhw.with_setpoint_bounds(target_temp);
+    }
+    map.insert(map.len().to_string(), dhw);
+
+    let mut climate = Component::new(
+        "Space Heating",
+        device_properties.base_topic().as_str(),
+        device_config.id.as_str(),
+        "climate",
+        "mdi:thermostat",
+    )
+    .climate(vec!["off", "heat", "cool", "auto"]);
+    if let Some(target_temp) = climate_target_temp {
+        climate = climate.with_setpoint_bounds(target_temp);
+    }
+    map.insert(map.len().to_string(), climate);
 
     map.insert(
         map.len().to_string(),
@@ -302,6 +416,210 @@ fn create_discovery_message(device_properties: &impl DeviceProperties) -> Discov
     }
 }
 
+/// Command handling for composite components (`water_heater`, `climate`,
+/// `select`) whose writes don't map 1:1 onto a single `RegisterMap`, unlike
+/// the plain register-driven components dispatched through `command_table`.
+enum Command {
+    /// Writes a boolean coil, comparing the payload against `on_value`.
+    SetCoil { register: u16, on_value: String },
+    /// Writes a holding register, scaling a decimal payload to register units.
+    SetScaledHolding { register: u16, scale: f64 },
+    /// Writes a holding register whose value is the payload's index in `options`.
+    SetHoldingOption { register: u16, options: Vec<String> },
+}
+
+impl Command {
+    async fn dispatch(&self, therma: &ThermaV, payload: &str) -> crate::Result<()> {
+        match self {
+            Command::SetCoil { register, on_value } => {
+                therma
+                    .set_coil(*register, payload.eq_ignore_ascii_case(on_value))
+                    .await
+            }
+            Command::SetScaledHolding { register, scale } => {
+                let value: f64 = payload
+                    .parse()
+                    .map_err(|err| format!("invalid payload '{payload}': {err}"))?;
+                therma
+                    .set_register(*register, (value * scale).round() as u16)
+                    .await
+            }
+            Command::SetHoldingOption { register, options } => {
+                let value = options
+                    .iter()
+                    .position(|option| option == payload)
+                    .ok_or_else(|| format!("unknown option: {payload}"))?;
+                therma.set_register(*register, value as u16).await
+            }
+        }
+    }
+}
+
+/// Builds the topic->`Command` dispatch table for composite components found
+/// in a discovery message, so new commandable components only need to be
+/// registered here alongside their `Component` builder.
+fn composite_commands(discovery: &Discovery) -> HashMap<String, Command> {
+    let mut commands = HashMap::new();
+    for component in discovery.components.values() {
+        match component.platform.as_str() {
+            "water_heater" if component.object_id.ends_with(".dhw") => {
+                if let Some(topic) = &component.mode_command_topic {
+                    commands.insert(
+                        topic.clone(),
+                        Command::SetCoil {
+                            register: coil::EnableDisableHeatingCooling::reg(),
+                            on_value: "heat_pump".to_string(),
+                        },
+                    );
+                }
+                if let Some(topic) = &component.temperature_command_topic {
+                    commands.insert(
+                        topic.clone(),
+                        Command::SetScaledHolding {
+                            register: holding::TargetTempHeatingCoolingCircuit2::reg(),
+                            scale: 10.0,
+                        },
+                    );
+                }
+            }
+            "climate" if component.object_id.ends_with(".climate") => {
+                if let Some(topic) = &component.mode_command_topic {
+                    commands.insert(
+                        topic.clone(),
+                        Command::SetHoldingOption {
+                            register: holding::OperationMode::reg(),
+                            options: component.modes.clone().unwrap_or_default(),
+                        },
+                    );
+                }
+                if let Some(topic) = &component.temperature_command_topic {
+                    commands.insert(
+                        topic.clone(),
+                        Command::SetScaledHolding {
+                            register: holding::TargetTempHeatingCoolingCircuit1::reg(),
+                            scale: 10.0,
+                        },
+                    );
+                }
+            }
+            "select" => {
+                if let (Some(topic), Some(options)) =
+                    (&component.command_topic, &component.options)
+                {
+                    commands.insert(
+                        topic.clone(),
+                        Command::SetHoldingOption {
+                            register: holding::EnergyStateInput::reg(),
+                            options: options.clone(),
+                        },
+                    );
+                }
+            }
+            _ => {}
+        }
+    }
+    commands
+}
+
+/// Payload of a `{base_topic}/request/{id}` message: a register name plus an
+/// optional value. A missing `value` means "read this register".
+#[derive(Deserialize)]
+struct RegisterRequest {
+    register: String,
+    #[serde(default)]
+    value: Option<serde_json::Value>,
+}
+
+/// Payload published back to `{base_topic}/response/{id}`.
+#[derive(Serialize)]
+struct RegisterResponse {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
+impl From<RegisterResponse> for Vec<u8> {
+    fn from(value: RegisterResponse) -> Self {
+        serde_json::to_vec(&value).unwrap_or_default()
+    }
+}
+
+/// Reads a register identified by name, trying each register kind in turn.
+/// The name is validated against `ThermaV`'s register table before any I/O
+/// is attempted, so an unknown name fails fast instead of as a bus timeout.
+async fn read_named_register(therma: &ThermaV, name: &str) -> crate::Result<serde_json::Value> {
+    if therma.coil_address(name).is_some() {
+        return therma.get_coil_by_name(name).await.map(serde_json::Value::Bool);
+    }
+    if therma.discrete_address(name).is_some() {
+        return therma
+            .get_discrete_by_name(name)
+            .await
+            .map(serde_json::Value::Bool);
+    }
+    if therma.holding_address(name).is_some() {
+        return therma
+            .get_holding_by_name(name)
+            .await
+            .map(|words| serde_json::json!(words));
+    }
+    if therma.input_address(name).is_some() {
+        return therma
+            .get_input_by_name(name)
+            .await
+            .map(|words| serde_json::json!(words));
+    }
+    Err(format!("unknown register: {name}"))
+}
+
+/// Handles one `{base_topic}/request/{id}` message: reads or writes the named
+/// register and returns the outcome to be published to `{base_topic}/response/{id}`.
+async fn handle_register_request(therma: &ThermaV, payload: &str) -> RegisterResponse {
+    let request: RegisterRequest = match serde_json::from_str(payload) {
+        Ok(request) => request,
+        Err(err) => {
+            return RegisterResponse {
+                value: None,
+                error: Some(format!("invalid request payload: {err}")),
+            }
+        }
+    };
+
+    let result = match request.value {
+        Some(serde_json::Value::Bool(value)) => {
+            if therma.coil_address(&request.register).is_none() {
+                Err(format!("unknown coil: {}", request.register))
+            } else {
+                therma
+                    .set_coil_by_name(&request.register, value)
+                    .await
+                    .map(|_| serde_json::Value::Bool(value))
+            }
+        }
+        Some(value) => match value.as_u64() {
+            Some(value) if therma.holding_address(&request.register).is_some() => therma
+                .set_register_by_name(&request.register, value as u16)
+                .await
+                .map(|_| serde_json::json!(value)),
+            Some(_) => Err(format!("unknown holding register: {}", request.register)),
+            None => Err("value must be a boolean or an unsigned integer".to_string()),
+        },
+        None => read_named_register(therma, &request.register).await,
+    };
+
+    match result {
+        Ok(value) => RegisterResponse {
+            value: Some(value),
+            error: None,
+        },
+        Err(error) => RegisterResponse {
+            value: None,
+            error: Some(error),
+        },
+    }
+}
+
 #[derive(Serialize, Clone)]
 struct BinarySensor(bool);
 
@@ -320,37 +638,32 @@ pub fn start_hass_mqtt_bridge_task(
     mqtt_client: mqtt::Client,
     mut modbus_rx: Receiver<(Register, String)>,
     mut mqtt_rx: Receiver<(String, String)>,
+    registers: Vec<RegisterMap>,
     signal: Arc<AtomicBool>,
 ) {
     let mqtt_client = Arc::new(tokio::sync::RwLock::new(mqtt_client));
     let therma_clone = therma.clone();
     let mut hass_client = Hass::new(mqtt_client.clone(), String::from(&therma.base_topic()));
     let forwarder_signal = signal.clone();
+    let discovery_message = create_discovery_message(&therma_clone, &registers);
+    let command_table: HashMap<String, RegisterMap> = registers
+        .iter()
+        .filter(|reg| reg.writable)
+        .map(|reg| {
+            let topic = format!(
+                "{}/{}.{}/set",
+                therma.base_topic(),
+                therma.id(),
+                slug(&reg.name)
+            );
+            (topic, reg.clone())
+        })
+        .collect();
+    let static_commands = composite_commands(&discovery_message);
+    let request_topic_prefix = format!("{}/request/", therma.base_topic());
 
     // mqtt -> modbus
     tokio::spawn(async move {
-        /**        tokio::time::sleep(tokio::time::Duration::from_secs(5)).await;
-                if let Err(err) = therma.set_coil(coil::EnableDisableHeatingCooling::reg(), true).await {
-                    log::error!(target: "mqtt-client", "failed to enable pump: {err}");
-                }
-                tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
-                if let Err(err) = therma.set_coil(coil::SilentModeSet::reg(), true).await {
-                    log::error!(target: "mqtt-client", "failed to enable silent mode: {err}");
-                }
-                tokio::time::sleep(tokio::time::Duration::from_millis(50)).await;
-                if let Err(err) = therma.set_register(holding::OperationMode::reg(), 4u16).await {
-                    log::error!(target: "mqtt-client", "failed to set operation mode to Heating: {err}");
-                }
-                tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
-                if let Err(err) = therma.set_register(holding::ControlMethod::reg(), 1u16).await {
-                    log::error!(target: "mqtt-client", "failed to set control mode to room air: {err}");
-                }
-
-                tokio::time::sleep(tokio::time::Duration::from_millis(100)).await;
-                if let Err(err) = therma.set_register(holding::EnergyStateInput::reg(), 2u16).await {
-                    log::error!(target: "mqtt-client", "failed to set EnergyStateInput to normal: {err}");
-                }
-        */
         loop {
             if forwarder_signal.load(std::sync::atomic::Ordering::Relaxed) {
                 return;
@@ -363,23 +676,111 @@ pub fn start_hass_mqtt_bridge_task(
                     continue;
                 }
             };
-            // TODO: rework separation of concern.
-            let _ = match topic.as_str() {
-                "ThermaV/thermav.dhw/temperature/set" => therma
-                    .set_coil(
-                        holding::TargetTempHeatingCoolingCircuit2::reg(),
-                        payload.eq("true"),
-                    )
+
+            if let Some(reg) = command_table.get(topic.as_str()) {
+                let write_result = match reg.kind {
+                    RegisterKind::Coil => therma
+                        .set_coil(reg.address, payload.eq("true") || payload.eq("1"))
+                        .await,
+                    RegisterKind::Holding => match payload.parse::<u16>() {
+                        Ok(value) => therma.set_register(reg.address, value).await,
+                        Err(err) => Err(format!("invalid payload for {}: {}", reg.name, err)),
+                    },
+                    RegisterKind::Discrete | RegisterKind::Input => {
+                        Err(format!("{} is read-only", reg.name))
+                    }
+                };
+
+                match write_result {
+                    Ok(()) => {
+                        let state_topic = topic.trim_end_matches("/set");
+                        if let Some(error) = rwlock_read_guard(&mqtt_client)
+                            .await
+                            .publish(state_topic.to_string(), payload.clone())
+                            .await
+                        {
+                            log::error!(target: "mqtt-client", "failed to echo {}: {error}", state_topic);
+                        }
+                    }
+                    Err(err) => {
+                        log::error!(target: "mqtt-client", "failed to write {}: {err}", reg.name)
+                    }
+                }
+                continue;
+            }
+
+            if let Some(command) = static_commands.get(topic.as_str()) {
+                match command.dispatch(&therma, &payload).await {
+                    Ok(()) => {
+                        let state_topic = topic.trim_end_matches("/set");
+                        if let Some(error) = rwlock_read_guard(&mqtt_client)
+                            .await
+                            .publish(state_topic.to_string(), payload.clone())
+                            .await
+                        {
+                            log::error!(target: "mqtt-client", "failed to echo {}: {error}", state_topic);
+                        }
+                    }
+                    Err(err) => {
+                        log::error!(target: "mqtt-client", "failed to handle {}: {err}", topic)
+                    }
+                }
+                continue;
+            }
+
+            if let Some(correlation_id) = topic.strip_prefix(request_topic_prefix.as_str()) {
+                let response = handle_register_request(&therma, &payload).await;
+                if let Err(error) = rwlock_read_guard(&mqtt_client)
                     .await
-                    .map_err(|err| err.to_string()),
-                &_ => Ok(()),
-            };
+                    .publish_with_base_topic(format!("response/{correlation_id}"), response)
+                    .await
+                {
+                    log::error!(target: "mqtt-client", "failed to publish register response: {error}");
+                }
+                continue;
+            }
 
-            println!("{}: {}", topic, payload);
+            log::warn!(target: "mqtt-client", "no command handler for {}: {}", topic, payload);
         }
     });
     tokio::spawn(async move {
-        let discovery_message = create_discovery_message(&therma_clone);
+        {
+            let reconnect_mqtt_client = mqtt_client.clone();
+            let reconnect_discovery = discovery_message.clone();
+            let reconnect_instance_name = therma_clone.base_topic();
+            let reconnect_device_id = therma_clone.id();
+            rwlock_read_guard(&mqtt_client)
+                .await
+                .on_reconnect(move || {
+                    let mqtt_client = reconnect_mqtt_client.clone();
+                    let discovery = reconnect_discovery.clone();
+                    let instance_name = reconnect_instance_name.clone();
+                    let device_id = reconnect_device_id.clone();
+                    tokio::spawn(async move {
+                        log::info!(target: "mqtt-client", "mqtt reconnected, republishing discovery and resubscribing");
+                        if let Err(error) = rwlock_read_guard(&mqtt_client)
+                            .await
+                            .publish_with_base_topic(
+                                format!("device/{}/config", device_id),
+                                discovery.clone(),
+                            )
+                            .await
+                        {
+                            log::error!(target: "mqtt-client", "failed to republish discovery after reconnect: {error}");
+                        }
+                        if let Err(err) = rwlock_write_guard(&mqtt_client)
+                            .await
+                            .subscribe(format!("{instance_name}/request/#"))
+                            .await
+                        {
+                            log::error!(target: "mqtt-client", "failed to resubscribe to register requests: {err}");
+                        }
+                        Hass::new(mqtt_client, instance_name).subscribe(discovery).await;
+                    });
+                })
+                .await;
+        }
+
         {
             let client = rwlock_read_guard(&mqtt_client).await;
             if let Err(error) = client
@@ -394,9 +795,21 @@ pub fn start_hass_mqtt_bridge_task(
         }
 
         hass_client.subscribe(discovery_message).await;
+        if let Err(err) = rwlock_write_guard(&mqtt_client)
+            .await
+            .subscribe(format!("{}/request/#", therma_clone.base_topic()))
+            .await
+        {
+            log::error!(target: "mqtt-client", "failed to subscribe to register requests: {err}");
+        }
 
         hass_client.publish_state(true).await;
+        rwlock_read_guard(&mqtt_client)
+            .await
+            .publish_availability(true)
+            .await;
         let mut state = BinarySensor(false);
+        let mut was_connected = true;
 
         if let Some(error) = hass_client
             .send_sensor_data("thermav.dhw/mode", "heat_pump")
@@ -411,6 +824,18 @@ pub fn start_hass_mqtt_bridge_task(
                 break;
             }
 
+            let is_connected = therma_clone.is_connected();
+            if is_connected != was_connected {
+                if let Some(error) = rwlock_read_guard(&mqtt_client)
+                    .await
+                    .publish_availability(is_connected)
+                    .await
+                {
+                    log::error!(target: "mqtt-client", "failed to publish availability: {error}");
+                }
+                was_connected = is_connected;
+            }
+
             #[cfg(not(feature = "io"))]
             {
                 if let Some(error) = hass_client
@@ -448,7 +873,9 @@ pub fn start_hass_mqtt_bridge_task(
                     }
                 }
                 Register::Holding(reg) => {
-                    let value = reg.1[0] as f64 * 0.1;
+                    let value = find_register(&registers, RegisterKind::Holding, reg.0)
+                        .map(|mapping| mapping.decode(&reg.1))
+                        .unwrap_or_else(|| reg.1[0] as f64 * 0.1);
                     log::info!(target: "mqtt-client", "{}={}",normalized_topic, value);
                     if let Some(error) = hass_client
                         .send_sensor_data(&normalized_topic, value.to_string())
@@ -458,7 +885,9 @@ pub fn start_hass_mqtt_bridge_task(
                     }
                 }
                 Register::Input(reg) => {
-                    let value = reg.1[0] as f64 * 0.1;
+                    let value = find_register(&registers, RegisterKind::Input, reg.0)
+                        .map(|mapping| mapping.decode(&reg.1))
+                        .unwrap_or_else(|| reg.1[0] as f64 * 0.1);
                     log::info!(target: "mqtt-client", "{}={}",normalized_topic, value);
                     if let Some(error) = hass_client
                         .send_sensor_data(&normalized_topic, value.to_string())
@@ -511,6 +940,12 @@ impl Hass {
                     log::error!(target: "mqtt-client", "failed to subscribe msg: {err}");
                 }
             }
+
+            if let Some(topic) = component.1.command_topic {
+                if let Err(err) = client.subscribe(topic).await {
+                    log::error!(target: "mqtt-client", "failed to subscribe msg: {err}");
+                }
+            }
         }
     }
 
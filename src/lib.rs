@@ -1,7 +1,10 @@
-use crate::config::{ThermaConfig, DEFAULT_BAUD_RATE, DEFAULT_TIMEOUT};
+use crate::config::{DueScheduler, RegisterKind, RegisterMap, ThermaConfig, DEFAULT_TIMEOUT};
+use crate::connection;
+use crate::connection::{ConnectionSupervisor, LinkState};
 use crate::hass::DeviceProperties;
 use crate::modbus::*;
 use crate::registers::{coil, discrete, holding, input, ModbusRegister};
+use crate::shutdown::Shutdown;
 use log::info;
 use std::ops::Deref;
 use std::result;
@@ -11,12 +14,68 @@ use std::time::Duration;
 use tokio::sync::mpsc::{Receiver, Sender};
 use tokio::sync::{mpsc, Mutex, RwLock};
 use tokio::time::timeout;
-use tokio_modbus::client::{rtu, Reader};
+use tokio_modbus::client::{self, Reader};
 use tokio_modbus::prelude::*;
-use tokio_modbus::Slave;
-use tokio_serial::SerialStream;
+
+/// Default tolerated gap (in reserved addresses) when folding registers into
+/// a batched read; see `group_registers`.
+const DEFAULT_REGISTER_GAP: u16 = 1;
+/// Modbus caps a single holding/input register read at 125 words.
+const MAX_HOLDING_SPAN: u16 = 125;
+/// Modbus caps a single coil/discrete-input read at 2000 bits.
+const MAX_COIL_SPAN: u16 = 2000;
+
+/// A span of consecutive addresses read in one Modbus transaction, together
+/// with the named registers it backs. Each member carries its own word width
+/// (1 for `u16`/`s16`, 2 for `u32`/`s32`/`f32`) so multi-word registers
+/// reserve the addresses they actually span.
+struct RegisterGroup {
+    members: Vec<(String, u16, u16)>,
+    base: u16,
+    count: u16,
+}
+
+/// Sorts `registers` (name, address, word width) by address and folds them
+/// into `RegisterGroup`s so the polling loop can read many registers with a
+/// single Modbus transaction. Two registers join the same group when the gap
+/// between them is at most `max_gap` reserved addresses and the resulting
+/// span doesn't exceed `max_span` (Modbus's per-transaction register/coil
+/// limit).
+fn group_registers(registers: &[(String, u16, u16)], max_gap: u16, max_span: u16) -> Vec<RegisterGroup> {
+    let mut sorted = registers.to_vec();
+    sorted.sort_by_key(|(_, addr, _)| *addr);
+
+    let mut groups: Vec<RegisterGroup> = Vec::new();
+    for (name, addr, width) in sorted {
+        let width = width.max(1);
+        if let Some(group) = groups.last_mut() {
+            let span_end = group.base + group.count - 1;
+            let gap = addr.saturating_sub(span_end + 1);
+            let new_count = addr + width - group.base;
+            if gap <= max_gap && new_count <= max_span {
+                group.count = new_count;
+                group.members.push((name, addr, width));
+                continue;
+            }
+        }
+        groups.push(RegisterGroup {
+            members: vec![(name, addr, width)],
+            base: addr,
+            count: width,
+        });
+    }
+    groups
+}
+
+/// Adapts a bit-addressed table (coils/discrete inputs, always one bit wide)
+/// to the `(name, address, width)` shape `group_registers` expects.
+fn single_bit_registers(registers: &[(String, u16)]) -> Vec<(String, u16, u16)> {
+    registers.iter().map(|(name, addr)| (name.clone(), *addr, 1)).collect()
+}
 
 pub mod config;
+pub mod connection;
+pub mod control;
 #[cfg(feature = "hass")]
 pub mod hass;
 mod modbus;
@@ -24,6 +83,9 @@ mod modbus;
 #[cfg(feature = "mqtt")]
 pub mod mqtt;
 pub mod registers;
+pub mod shutdown;
+#[cfg(feature = "sim")]
+pub mod sim;
 
 pub type Result<T> = result::Result<T, String>;
 
@@ -46,11 +108,18 @@ pub struct ThermaV {
     sender: Sender<(Register, String)>,
     discrete_registers: Vec<(String, u16)>,
     coils: Vec<(String, u16)>,
-    holding_registers: Vec<(String, u16)>,
-    input_registers: Vec<(String, u16)>,
+    /// (topic name, address, word width) — width is 2 for a config-driven
+    /// `u32`/`s32`/`f32` register, 1 otherwise; see `apply_register_config`.
+    holding_registers: Vec<(String, u16, u16)>,
+    input_registers: Vec<(String, u16, u16)>,
+    /// Full configured register table (data type, scale, bounds, ...), kept
+    /// alongside the per-kind name/address tables for `get_typed_by_name`.
+    registers: Vec<RegisterMap>,
     cfg: ThermaConfig,
     ctx: Option<Arc<Mutex<client::Context>>>,
     req_timeout: Duration,
+    connected: Arc<AtomicBool>,
+    supervisor: ConnectionSupervisor,
 }
 
 #[derive(Clone, Debug)]
@@ -101,7 +170,10 @@ impl ThermaV {
                     holding::DHWTargetTemp::structure(),
                     holding::ShiftValueTargetInAutoModeCircuit1::structure(),
                     holding::ShiftValueTargetInAutoModeCircuit2::structure(),
-                ],
+                ]
+                .into_iter()
+                .map(|(name, addr)| (name, addr, 1))
+                .collect(),
                 input_registers: vec![
                     input::ErrorCode::structure(),
                     input::WaterInletTemperature::structure(),
@@ -114,9 +186,15 @@ impl ThermaV {
                     input::FlowTemperatureCircuit2::structure(),
                     input::RoomAirTemperatureCircuit2::structure(),
                     input::OutdoorAirTemperature::structure(),
-                ],
+                ]
+                .into_iter()
+                .map(|(name, addr)| (name, addr, 1))
+                .collect(),
+                registers: Vec::new(),
                 ctx: ctx.clone(),
                 req_timeout: Duration::from_millis(cfg.timeout_ms),
+                connected: Arc::new(AtomicBool::new(true)),
+                supervisor: ConnectionSupervisor::idle(),
             },
             receiver,
         )
@@ -124,56 +202,108 @@ impl ThermaV {
 
     pub async fn new(
         cfg: ThermaConfig,
-        shutdown_listener: Arc<AtomicBool>,
+        registers: Vec<RegisterMap>,
+        shutdown: Shutdown,
     ) -> Result<(Self, Receiver<(Register, String)>)> {
         let mut thread_safe_ctx: Option<Arc<Mutex<client::Context>>> = None;
         #[cfg(feature = "io")]
         {
-            let slave = Slave(cfg.slave_id);
-            let builder = tokio_serial::new(cfg.tty_path.clone(), DEFAULT_BAUD_RATE)
-                .timeout(Duration::from_millis(cfg.timeout_ms));
-            let port = SerialStream::open(&builder).unwrap();
-            let mut ctx = rtu::attach_slave(port, slave);
-            thread_safe_ctx = Some(Arc::new(Mutex::new(ctx)));
+            // A failed initial connect no longer panics the process: we start
+            // up with no context and let the caller retry (e.g. by restarting
+            // the service) rather than wedging on an unplugged adapter. The
+            // `ConnectionSupervisor` only covers reconnects after a context
+            // already exists, so this case is handled separately here.
+            match connection::reopen(&cfg) {
+                Ok(ctx) => thread_safe_ctx = Some(Arc::new(Mutex::new(ctx))),
+                Err(err) => {
+                    log::error!(target: "modbus", "Unable to open serial port {}: {}", cfg.tty_path, err);
+                }
+            }
+        }
+        let (mut therma_instance, receiver) = Self::default(cfg, thread_safe_ctx);
+        therma_instance.apply_register_config(&registers);
+        therma_instance.registers = registers.clone();
+        if let Some(ctx) = therma_instance.ctx.clone() {
+            therma_instance.supervisor = ConnectionSupervisor::spawn(
+                therma_instance.cfg.clone(),
+                ctx,
+                therma_instance.connected.clone(),
+                shutdown.clone(),
+            )
+            .await;
         }
-        let (therma_instance, receiver) = Self::default(cfg, thread_safe_ctx);
         let instance = therma_instance.clone();
         let tx = instance.sender.clone();
         #[cfg(not(feature = "io"))]
-        tokio::spawn(async move {
-            let (topic, reg) = holding::TargetTempHeatingCoolingCircuit2::structure();
-            while !shutdown_listener.load(Ordering::Relaxed) {
-                let topic = remap_topic_from_modbus(topic.clone());
-                if topic.eq("dhw/temperature") {
-                    tx.send((Register::Holding(HoldingRegister(reg, vec![43])), topic))
-                        .await;
-                }
-                let (topic, reg) = input::RoomAirTemperatureCircuit2::structure();
-                let topic = remap_topic_from_modbus(topic.clone());
-                if topic.eq("dhw/current_temperature") {
-                    tx.send((Register::Holding(HoldingRegister(reg, vec![35])), topic))
-                        .await;
+        {
+            let mut shutdown_rx = shutdown.listener();
+            let handle = tokio::spawn(async move {
+                let (topic, reg) = holding::TargetTempHeatingCoolingCircuit2::structure();
+                while !shutdown_rx.is_shutdown() {
+                    let topic = remap_topic_from_modbus(topic.clone());
+                    if topic.eq("dhw/temperature") {
+                        tx.send((Register::Holding(HoldingRegister(reg, vec![43])), topic))
+                            .await;
+                    }
+                    let (topic, reg) = input::RoomAirTemperatureCircuit2::structure();
+                    let topic = remap_topic_from_modbus(topic.clone());
+                    if topic.eq("dhw/current_temperature") {
+                        tx.send((Register::Holding(HoldingRegister(reg, vec![35])), topic))
+                            .await;
+                    }
+                    tokio::select! {
+                        _ = tokio::time::sleep(Duration::from_millis(2000)) => {},
+                        _ = shutdown_rx.recv() => break,
+                    }
                 }
-                tokio::time::sleep(Duration::from_millis(2000)).await;
-            }
-        });
+            });
+            shutdown.register(handle).await;
+        }
         #[cfg(feature = "io")]
-        tokio::spawn(async move {
+        {
+            let mut shutdown_rx = shutdown.listener();
+            let handle = tokio::spawn(async move {
             instance.initialize_bus().await;
 
             let sleep_booleans_ms = Duration::from_millis(500);
             let sleep_ms = Duration::from_millis(50);
-            while !shutdown_listener.load(Ordering::Relaxed) {
-                for (topic, reg) in instance.coils.clone() {
-                    match instance.get_coil(reg).await {
-                        Ok(value) => {
-                            match tx
-                                .send((Register::Coil(CoilRegister(reg, value)), topic))
-                                .await
-                            {
-                                Ok(_) => info!(target: "modbus:coil", "reg {}={}", reg, value),
-                                Err(err) => {
-                                    log::error!(target: "modbus:coil", "forwarding failed: {}", err)
+            let mut scheduler = DueScheduler::new(&registers);
+            let coil_groups = group_registers(
+                &single_bit_registers(&instance.coils),
+                DEFAULT_REGISTER_GAP,
+                MAX_COIL_SPAN,
+            );
+            let discrete_groups = group_registers(
+                &single_bit_registers(&instance.discrete_registers),
+                DEFAULT_REGISTER_GAP,
+                MAX_COIL_SPAN,
+            );
+            let input_groups =
+                group_registers(&instance.input_registers, DEFAULT_REGISTER_GAP, MAX_HOLDING_SPAN);
+            let holding_groups =
+                group_registers(&instance.holding_registers, DEFAULT_REGISTER_GAP, MAX_HOLDING_SPAN);
+            while !shutdown_rx.is_shutdown() {
+                for group in &coil_groups {
+                    let due: Vec<&(String, u16, u16)> = group
+                        .members
+                        .iter()
+                        .filter(|(_, reg, _)| scheduler.due(RegisterKind::Coil, *reg))
+                        .collect();
+                    if due.is_empty() {
+                        continue;
+                    }
+                    match instance.get_coil_batch(group.base, group.count).await {
+                        Ok(values) => {
+                            for (topic, reg, _) in due {
+                                let value = values[(reg - group.base) as usize];
+                                match tx
+                                    .send((Register::Coil(CoilRegister(*reg, value)), topic.clone()))
+                                    .await
+                                {
+                                    Ok(_) => info!(target: "modbus:coil", "reg {}={}", reg, value),
+                                    Err(err) => {
+                                        log::error!(target: "modbus:coil", "forwarding failed: {}", err)
+                                    }
                                 }
                             }
                         }
@@ -184,16 +314,30 @@ impl ThermaV {
                     tokio::time::sleep(sleep_booleans_ms).await;
                 }
 
-                for (topic, reg) in instance.discrete_registers.clone() {
-                    match instance.get_discrete(reg).await {
-                        Ok(value) => {
-                            match tx
-                                .send((Register::Discrete(DiscreteRegister(reg, value)), topic))
-                                .await
-                            {
-                                Ok(_) => info!(target: "modbus:discrete", "reg {}={}", reg, value),
-                                Err(err) => {
-                                    log::error!(target: "modbus:discrete", "forwarding failed: {}", err)
+                for group in &discrete_groups {
+                    let due: Vec<&(String, u16, u16)> = group
+                        .members
+                        .iter()
+                        .filter(|(_, reg, _)| scheduler.due(RegisterKind::Discrete, *reg))
+                        .collect();
+                    if due.is_empty() {
+                        continue;
+                    }
+                    match instance.get_discrete_batch(group.base, group.count).await {
+                        Ok(values) => {
+                            for (topic, reg, _) in due {
+                                let value = values[(reg - group.base) as usize];
+                                match tx
+                                    .send((
+                                        Register::Discrete(DiscreteRegister(*reg, value)),
+                                        topic.clone(),
+                                    ))
+                                    .await
+                                {
+                                    Ok(_) => info!(target: "modbus:discrete", "reg {}={}", reg, value),
+                                    Err(err) => {
+                                        log::error!(target: "modbus:discrete", "forwarding failed: {}", err)
+                                    }
                                 }
                             }
                         }
@@ -204,21 +348,34 @@ impl ThermaV {
                     tokio::time::sleep(sleep_booleans_ms).await;
                 }
 
-                for (topic, reg) in instance.input_registers.clone() {
-                    match instance.get_input(reg).await {
-                        Ok(value) => {
-                            match tx
-                                .send((
-                                    Register::Input(InputRegister(reg, value.clone())),
-                                    topic.clone(),
-                                ))
-                                .await
-                            {
-                                Ok(_) => {
-                                    info!(target: "modbus:input", "{}/{}={:?}", reg, topic, value);
-                                }
-                                Err(err) => {
-                                    log::error!(target: "modbus:input", "{}", err)
+                for group in &input_groups {
+                    let due: Vec<&(String, u16, u16)> = group
+                        .members
+                        .iter()
+                        .filter(|(_, reg, _)| scheduler.due(RegisterKind::Input, *reg))
+                        .collect();
+                    if due.is_empty() {
+                        continue;
+                    }
+                    match instance.get_input_batch(group.base, group.count).await {
+                        Ok(values) => {
+                            for (topic, reg, width) in due {
+                                let offset = (reg - group.base) as usize;
+                                let value = values[offset..offset + *width as usize].to_vec();
+                                let topic = remap_topic_from_modbus(topic.clone());
+                                match tx
+                                    .send((
+                                        Register::Input(InputRegister(*reg, value.clone())),
+                                        topic.clone(),
+                                    ))
+                                    .await
+                                {
+                                    Ok(_) => {
+                                        info!(target: "modbus:input", "{}/{}={:?}", reg, topic, value);
+                                    }
+                                    Err(err) => {
+                                        log::error!(target: "modbus:input", "{}", err)
+                                    }
                                 }
                             }
                         }
@@ -229,61 +386,71 @@ impl ThermaV {
                     tokio::time::sleep(sleep_ms).await;
                 }
 
-                for (topic, reg) in instance.holding_registers.clone() {
-                    match instance.get_holding(reg).await {
-                        Ok(value) => {
-                            let topic = remap_topic_from_modbus(topic);
-                            match tx
-                                .send((
-                                    Register::Holding(HoldingRegister(reg, value.clone())),
-                                    topic,
-                                ))
-                                .await
-                            {
-                                Ok(_) => {
-                                    if reg == holding::OperationMode::reg() {
-                                        info!(target: "modbus:holding", "OperationMode {}={:?}", reg, holding::OperationMode::from(value.clone()));
-                                    }
-                                    if reg == holding::ControlMethod::reg() {
-                                        info!(target: "modbus:holding", "ControlMethod {}={:?}", reg, holding::ControlMethod::from(value.clone()));
-                                    }
-                                    if reg == holding::EnergyStateInput::reg() {
-                                        info!(target: "modbus:holding", "EnergyStateInput {}={:?}", reg, holding::EnergyStateInput::from(value.clone()));
-                                    }
-                                    if reg == holding::TargetTempHeatingCoolingCircuit1::reg() {
-                                        info!(target: "modbus:holding", "TargetTempHeatingCoolingCircuit1 {}={:?}", reg, holding::TargetTempHeatingCoolingCircuit1::from(value.clone()));
-                                    }
-                                    if reg == holding::TargetTempHeatingCoolingCircuit2::reg() {
-                                        info!(target: "modbus:holding", "TargetTempHeatingCoolingCircuit2 {}={:?}", reg, holding::TargetTempHeatingCoolingCircuit2::from(value.clone()));
-                                    }
-                                    if reg == holding::RoomAirTempCircuit1::reg() {
-                                        info!(target: "modbus:holding", "RoomAirTempCircuit1 {}={:?}", reg, holding::RoomAirTempCircuit1::from(value.clone()));
-                                    }
-                                    if reg == holding::RoomAirTempCircuit2::reg() {
-                                        info!(target: "modbus:holding", "RoomAirTempCircuit2 {}={:?}", reg, holding::RoomAirTempCircuit2::from(value.clone()));
-                                    }
-                                    if reg == holding::DHWTargetTemp::reg() {
-                                        info!(target: "modbus:holding", "DHWTargetTemp {}={:?}", reg, holding::DHWTargetTemp::from(value.clone()));
-                                    }
-                                    if reg == holding::ShiftValueTargetInAutoModeCircuit1::reg() {
-                                        info!(target: "modbus:holding", "ShiftValueTargetInAutoModeCircuit1 {}={:?}", reg, holding::ShiftValueTargetInAutoModeCircuit1::from(value.clone()));
+                for group in &holding_groups {
+                    let due: Vec<&(String, u16, u16)> = group
+                        .members
+                        .iter()
+                        .filter(|(_, reg, _)| scheduler.due(RegisterKind::Holding, *reg))
+                        .collect();
+                    if due.is_empty() {
+                        continue;
+                    }
+                    match instance.get_holding_batch(group.base, group.count).await {
+                        Ok(values) => {
+                            for (topic, reg, width) in due {
+                                let reg = *reg;
+                                let offset = (reg - group.base) as usize;
+                                let value = values[offset..offset + *width as usize].to_vec();
+                                let topic = remap_topic_from_modbus(topic.clone());
+                                match tx
+                                    .send((Register::Holding(HoldingRegister(reg, value.clone())), topic))
+                                    .await
+                                {
+                                    Ok(_) => {
+                                        if reg == holding::OperationMode::reg() {
+                                            info!(target: "modbus:holding", "OperationMode {}={:?}", reg, holding::OperationMode::from(value.clone()));
+                                        }
+                                        if reg == holding::ControlMethod::reg() {
+                                            info!(target: "modbus:holding", "ControlMethod {}={:?}", reg, holding::ControlMethod::from(value.clone()));
+                                        }
+                                        if reg == holding::EnergyStateInput::reg() {
+                                            info!(target: "modbus:holding", "EnergyStateInput {}={:?}", reg, holding::EnergyStateInput::from(value.clone()));
+                                        }
+                                        if reg == holding::TargetTempHeatingCoolingCircuit1::reg() {
+                                            info!(target: "modbus:holding", "TargetTempHeatingCoolingCircuit1 {}={:?}", reg, holding::TargetTempHeatingCoolingCircuit1::from(value.clone()));
+                                        }
+                                        if reg == holding::TargetTempHeatingCoolingCircuit2::reg() {
+                                            info!(target: "modbus:holding", "TargetTempHeatingCoolingCircuit2 {}={:?}", reg, holding::TargetTempHeatingCoolingCircuit2::from(value.clone()));
+                                        }
+                                        if reg == holding::RoomAirTempCircuit1::reg() {
+                                            info!(target: "modbus:holding", "RoomAirTempCircuit1 {}={:?}", reg, holding::RoomAirTempCircuit1::from(value.clone()));
+                                        }
+                                        if reg == holding::RoomAirTempCircuit2::reg() {
+                                            info!(target: "modbus:holding", "RoomAirTempCircuit2 {}={:?}", reg, holding::RoomAirTempCircuit2::from(value.clone()));
+                                        }
+                                        if reg == holding::DHWTargetTemp::reg() {
+                                            info!(target: "modbus:holding", "DHWTargetTemp {}={:?}", reg, holding::DHWTargetTemp::from(value.clone()));
+                                        }
+                                        if reg == holding::ShiftValueTargetInAutoModeCircuit1::reg() {
+                                            info!(target: "modbus:holding", "ShiftValueTargetInAutoModeCircuit1 {}={:?}", reg, holding::ShiftValueTargetInAutoModeCircuit1::from(value.clone()));
+                                        }
+                                        if reg == holding::ShiftValueTargetInAutoModeCircuit2::reg() {
+                                            info!(target: "modbus:holding", "ShiftValueTargetInAutoModeCircuit2 {}={:?}", reg, holding::ShiftValueTargetInAutoModeCircuit1::from(value.clone()));
+                                        }
                                     }
-                                    if reg == holding::ShiftValueTargetInAutoModeCircuit2::reg() {
-                                        info!(target: "modbus:holding", "ShiftValueTargetInAutoModeCircuit2 {}={:?}", reg, holding::ShiftValueTargetInAutoModeCircuit1::from(value.clone()));
+                                    Err(err) => {
+                                        log::error!(target: "modbus:holding", "forwarding failed: {}", err)
                                     }
                                 }
-                                Err(err) => {
-                                    log::error!(target: "modbus:holding", "forwarding failed: {}", err)
-                                }
                             }
                         }
                         Err(err) => {
                             #[cfg(not(feature = "io"))]
-                            {
-                                let topic = remap_topic_from_modbus(topic);
+                            for (topic, reg, _) in &due {
+                                let topic = remap_topic_from_modbus(topic.clone());
                                 if topic.eq("dhw/temperature") {
                                     tx.send((
-                                        Register::Holding(HoldingRegister(reg, vec![0xA1])),
+                                        Register::Holding(HoldingRegister(*reg, vec![0xA1])),
                                         topic,
                                     ))
                                     .await;
@@ -294,9 +461,14 @@ impl ThermaV {
                     }
                     tokio::time::sleep(sleep_ms).await;
                 }
-                tokio::time::sleep(Duration::from_millis(2000)).await;
+                tokio::select! {
+                    _ = tokio::time::sleep(Duration::from_millis(2000)) => {},
+                    _ = shutdown_rx.recv() => break,
+                }
             }
-        });
+            });
+            shutdown.register(handle).await;
+        }
 
         Ok((therma_instance, receiver))
     }
@@ -344,11 +516,280 @@ impl ThermaV {
         Err(format!("set failed 0x{:02x}", reg))
     }
 
+    /// Overrides the built-in register tables with entries from `registers`,
+    /// one `RegisterKind` at a time. A kind with no configured entries keeps
+    /// the hardcoded defaults from `ThermaV::default`, so an empty or absent
+    /// config file falls back to the built-in register set unchanged.
+    ///
+    /// Holding/input entries carry their `word_count()` alongside the
+    /// address, so a config-driven `u32`/`s32`/`f32` register reserves the
+    /// two words it actually spans in `group_registers` and the polling loop,
+    /// instead of being silently truncated to the first word.
+    fn apply_register_config(&mut self, registers: &[RegisterMap]) {
+        for kind in [RegisterKind::Coil, RegisterKind::Discrete] {
+            let mapped: Vec<(String, u16)> = registers
+                .iter()
+                .filter(|reg| reg.kind == kind)
+                .map(|reg| (reg.name.clone(), reg.address))
+                .collect();
+            if mapped.is_empty() {
+                continue;
+            }
+            match kind {
+                RegisterKind::Coil => self.coils = mapped,
+                RegisterKind::Discrete => self.discrete_registers = mapped,
+                RegisterKind::Holding | RegisterKind::Input => unreachable!(),
+            }
+        }
+        for kind in [RegisterKind::Holding, RegisterKind::Input] {
+            let mapped: Vec<(String, u16, u16)> = registers
+                .iter()
+                .filter(|reg| reg.kind == kind)
+                .map(|reg| (reg.name.clone(), reg.address, reg.word_count() as u16))
+                .collect();
+            if mapped.is_empty() {
+                continue;
+            }
+            match kind {
+                RegisterKind::Holding => self.holding_registers = mapped,
+                RegisterKind::Input => self.input_registers = mapped,
+                RegisterKind::Coil | RegisterKind::Discrete => unreachable!(),
+            }
+        }
+    }
+
+    /// Looks up a coil's address by its topic name, as configured via
+    /// the config file's `registers` table or, absent that, `ThermaV::default`'s
+    /// built-in table.
+    pub fn coil_address(&self, name: &str) -> Option<u16> {
+        self.coils
+            .iter()
+            .find(|(topic, _)| topic == name)
+            .map(|(_, addr)| *addr)
+    }
+
+    /// Looks up a holding register's address by its topic name.
+    pub fn holding_address(&self, name: &str) -> Option<u16> {
+        self.holding_registers
+            .iter()
+            .find(|(topic, _, _)| topic == name)
+            .map(|(_, addr, _)| *addr)
+    }
+
+    /// Looks up a discrete input's address by its topic name.
+    pub fn discrete_address(&self, name: &str) -> Option<u16> {
+        self.discrete_registers
+            .iter()
+            .find(|(topic, _)| topic == name)
+            .map(|(_, addr)| *addr)
+    }
+
+    /// Looks up an input register's address by its topic name.
+    pub fn input_address(&self, name: &str) -> Option<u16> {
+        self.input_registers
+            .iter()
+            .find(|(topic, _, _)| topic == name)
+            .map(|(_, addr, _)| *addr)
+    }
+
+    /// Writes a coil identified by its topic name.
+    pub async fn set_coil_by_name(&self, name: &str, value: bool) -> Result<()> {
+        let reg = self
+            .coil_address(name)
+            .ok_or_else(|| format!("unknown coil: {}", name))?;
+        self.set_coil(reg, value).await
+    }
+
+    /// Writes a holding register identified by its topic name.
+    pub async fn set_register_by_name(&self, name: &str, value: u16) -> Result<()> {
+        let reg = self
+            .holding_address(name)
+            .ok_or_else(|| format!("unknown holding register: {}", name))?;
+        self.set_register(reg, value).await
+    }
+
+    /// Reads a coil identified by its topic name.
+    pub async fn get_coil_by_name(&self, name: &str) -> Result<bool> {
+        let reg = self
+            .coil_address(name)
+            .ok_or_else(|| format!("unknown coil: {}", name))?;
+        self.get_coil(reg).await
+    }
+
+    /// Reads a discrete input identified by its topic name.
+    pub async fn get_discrete_by_name(&self, name: &str) -> Result<bool> {
+        let reg = self
+            .discrete_address(name)
+            .ok_or_else(|| format!("unknown discrete input: {}", name))?;
+        self.get_discrete(reg).await
+    }
+
+    /// Reads a holding register identified by its topic name.
+    pub async fn get_holding_by_name(&self, name: &str) -> Result<Vec<u16>> {
+        let reg = self
+            .holding_address(name)
+            .ok_or_else(|| format!("unknown holding register: {}", name))?;
+        self.get_holding(reg).await
+    }
+
+    /// Reads an input register identified by its topic name.
+    pub async fn get_input_by_name(&self, name: &str) -> Result<Vec<u16>> {
+        let reg = self
+            .input_address(name)
+            .ok_or_else(|| format!("unknown input register: {}", name))?;
+        self.get_input(reg).await
+    }
+
+    /// Fetches the raw words backing `reg` (1 word for 16-bit types, 2 for
+    /// 32-bit/float), the shared read path behind `get_typed`/`get_measurement`.
+    async fn get_register_words(&self, reg: &RegisterMap) -> Result<Vec<u16>> {
+        match reg.kind {
+            RegisterKind::Holding => {
+                self.get_holding_batch(reg.address, reg.word_count() as u16)
+                    .await
+            }
+            RegisterKind::Input => {
+                self.get_input_batch(reg.address, reg.word_count() as u16)
+                    .await
+            }
+            RegisterKind::Coil | RegisterKind::Discrete => Err(format!(
+                "'{}' is a boolean register, use get_coil/get_discrete",
+                reg.name
+            )),
+        }
+    }
+
+    /// Looks up `name` in the configured register table, shared by the
+    /// `*_by_name` read/write helpers below.
+    fn find_register_config(&self, name: &str) -> Result<&RegisterMap> {
+        self.registers
+            .iter()
+            .find(|reg| reg.name == name)
+            .ok_or_else(|| format!("unknown register: {}", name))
+    }
+
+    /// Reads `reg` using its declared `data_type`/`scale`/`swap_words`,
+    /// fetching the correct word count (1 for 16-bit, 2 for 32-bit/float) and
+    /// returning a single decoded, scaled value rather than raw register words.
+    pub async fn get_typed(&self, reg: &RegisterMap) -> Result<f64> {
+        let words = self.get_register_words(reg).await?;
+        Ok(reg.decode(&words))
+    }
+
+    /// Looks up `name` in the configured register table and decodes it via
+    /// `get_typed`.
+    pub async fn get_typed_by_name(&self, name: &str) -> Result<f64> {
+        self.get_typed(self.find_register_config(name)?).await
+    }
+
+    /// Looks up `name`'s full configured mapping (data type, scale, bounds),
+    /// for callers that need to validate a value before writing it.
+    pub fn register_config_by_name(&self, name: &str) -> Option<&RegisterMap> {
+        self.registers.iter().find(|reg| reg.name == name)
+    }
+
+    /// Writes `value` (engineering units) to `reg`, applying the inverse of
+    /// its configured `scale` before writing the raw word — the write-side
+    /// counterpart of `get_typed`/`RegisterMap::decode`, which computes
+    /// `raw * scale = value`. Multi-word registers aren't supported, since
+    /// `set_register` writes a single holding register.
+    pub async fn set_typed(&self, reg: &RegisterMap, value: f64) -> Result<()> {
+        if reg.kind != RegisterKind::Holding {
+            return Err(format!("'{}' is not a writable holding register", reg.name));
+        }
+        if reg.word_count() != 1 {
+            return Err(format!(
+                "'{}' spans {} words; scaled multi-word writes aren't supported",
+                reg.name,
+                reg.word_count()
+            ));
+        }
+        let scale = reg.scale.unwrap_or(1.0);
+        self.set_register(reg.address, (value / scale).round() as u16)
+            .await
+    }
+
+    /// Looks up `name` in the configured register table and writes it via
+    /// `set_typed`.
+    pub async fn set_typed_by_name(&self, name: &str, value: f64) -> Result<()> {
+        self.set_typed(self.find_register_config(name)?, value).await
+    }
+
+    /// Like `get_typed`, but returns the value paired with its configured
+    /// unit instead of a bare `f64`.
+    pub async fn get_measurement(&self, reg: &RegisterMap) -> Result<config::Measurement> {
+        let words = self.get_register_words(reg).await?;
+        Ok(reg.decode_measurement(&words))
+    }
+
+    /// Looks up `name` in the configured register table and decodes it, with
+    /// its configured unit attached.
+    pub async fn get_measurement_by_name(&self, name: &str) -> Result<config::Measurement> {
+        self.get_measurement(self.find_register_config(name)?).await
+    }
+
+    /// Runs weather-compensation control: smooths `outdoor_register`, maps it
+    /// to a flow setpoint via the configured heating curve, optionally closes
+    /// the loop with a PID against `measured_flow_register`, and writes the
+    /// result to `target_register` every `POLL_INTERVAL`. Returns early if
+    /// `cfg.enabled` is false.
+    pub async fn spawn_weather_compensation(&self, cfg: config::ControlConfig, shutdown: Shutdown) {
+        const POLL_INTERVAL: Duration = Duration::from_secs(30);
+        if !cfg.enabled {
+            return;
+        }
+        let mut control = match control::WeatherCompensation::from_config(&cfg) {
+            Some(control) => control,
+            None => {
+                log::error!(target: "control", "heating curve needs at least one point; not starting weather compensation");
+                return;
+            }
+        };
+        let instance = self.clone();
+        let mut shutdown_rx = shutdown.listener();
+        let handle = tokio::spawn(async move {
+            let dt_secs = POLL_INTERVAL.as_secs_f64();
+            while !shutdown_rx.is_shutdown() {
+                tokio::select! {
+                    _ = tokio::time::sleep(POLL_INTERVAL) => {},
+                    _ = shutdown_rx.recv() => break,
+                }
+                let outdoor = match instance.get_typed_by_name(&cfg.outdoor_register).await {
+                    Ok(value) => value,
+                    Err(err) => {
+                        log::warn!(target: "control", "unable to read {}: {}", cfg.outdoor_register, err);
+                        continue;
+                    }
+                };
+                let measured_flow = if cfg.pid.is_some() {
+                    match instance.get_typed_by_name(&cfg.measured_flow_register).await {
+                        Ok(value) => value,
+                        Err(err) => {
+                            log::warn!(target: "control", "unable to read {}: {}", cfg.measured_flow_register, err);
+                            continue;
+                        }
+                    }
+                } else {
+                    0.0
+                };
+                let setpoint = control.step(outdoor, measured_flow, dt_secs);
+                if let Err(err) = instance
+                    .set_typed_by_name(&cfg.target_register, setpoint)
+                    .await
+                {
+                    log::warn!(target: "control", "unable to write {}: {}", cfg.target_register, err);
+                }
+            }
+        });
+        shutdown.register(handle).await;
+    }
+
     pub async fn get_coil(&self, reg: u16) -> Result<bool> {
         if let Some(ctx) = &self.ctx {
             if let Ok(Ok(Ok(result))) =
                 timeout(self.req_timeout, ctx.lock().await.read_coils(reg, 1)).await
             {
+                self.connected.store(true, Ordering::Relaxed);
                 return Ok(result[0]);
             }
         }
@@ -357,9 +798,11 @@ impl ThermaV {
             if let Ok(Ok(Ok(result))) =
                 timeout(self.req_timeout, ctx.lock().await.read_coils(reg, 1)).await
             {
+                self.connected.store(true, Ordering::Relaxed);
                 return Ok(result[0]);
             }
         }
+        self.connected.store(false, Ordering::Relaxed);
         Err(format!("read failed 0x{:02x}", reg))
     }
 
@@ -371,6 +814,7 @@ impl ThermaV {
             )
             .await
             {
+                self.connected.store(true, Ordering::Relaxed);
                 return Ok(result[0]);
             }
         }
@@ -382,9 +826,11 @@ impl ThermaV {
             )
             .await
             {
+                self.connected.store(true, Ordering::Relaxed);
                 return Ok(result[0]);
             }
         }
+        self.connected.store(false, Ordering::Relaxed);
         Err(format!("read failed 0x{:02x}", reg))
     }
 
@@ -396,6 +842,7 @@ impl ThermaV {
             )
             .await
             {
+                self.connected.store(true, Ordering::Relaxed);
                 return Ok(result);
             }
         }
@@ -407,9 +854,11 @@ impl ThermaV {
             )
             .await
             {
+                self.connected.store(true, Ordering::Relaxed);
                 return Ok(result);
             }
         }
+        self.connected.store(false, Ordering::Relaxed);
         Err(format!("read failed 0x{:02x}", reg))
     }
 
@@ -421,6 +870,7 @@ impl ThermaV {
             )
             .await
             {
+                self.connected.store(true, Ordering::Relaxed);
                 return Ok(result);
             }
         }
@@ -432,17 +882,147 @@ impl ThermaV {
             )
             .await
             {
+                self.connected.store(true, Ordering::Relaxed);
                 return Ok(result);
             }
         }
+        self.connected.store(false, Ordering::Relaxed);
         Err(format!("read failed 0x{:02x}", reg))
     }
+
+    /// Reads `count` consecutive coils starting at `base` in a single Modbus
+    /// transaction, as grouped by `group_registers`.
+    pub async fn get_coil_batch(&self, base: u16, count: u16) -> Result<Vec<bool>> {
+        if let Some(ctx) = &self.ctx {
+            if let Ok(Ok(Ok(result))) =
+                timeout(self.req_timeout, ctx.lock().await.read_coils(base, count)).await
+            {
+                self.connected.store(true, Ordering::Relaxed);
+                return Ok(result);
+            }
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        if let Some(ctx) = &self.ctx {
+            if let Ok(Ok(Ok(result))) =
+                timeout(self.req_timeout, ctx.lock().await.read_coils(base, count)).await
+            {
+                self.connected.store(true, Ordering::Relaxed);
+                return Ok(result);
+            }
+        }
+        self.connected.store(false, Ordering::Relaxed);
+        Err(format!("batch read failed 0x{:02x}..+{}", base, count))
+    }
+
+    /// Reads `count` consecutive discrete inputs starting at `base` in a
+    /// single Modbus transaction, as grouped by `group_registers`.
+    pub async fn get_discrete_batch(&self, base: u16, count: u16) -> Result<Vec<bool>> {
+        if let Some(ctx) = &self.ctx {
+            if let Ok(Ok(Ok(result))) = timeout(
+                self.req_timeout,
+                ctx.lock().await.read_discrete_inputs(base, count),
+            )
+            .await
+            {
+                self.connected.store(true, Ordering::Relaxed);
+                return Ok(result);
+            }
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        if let Some(ctx) = &self.ctx {
+            if let Ok(Ok(Ok(result))) = timeout(
+                self.req_timeout,
+                ctx.lock().await.read_discrete_inputs(base, count),
+            )
+            .await
+            {
+                self.connected.store(true, Ordering::Relaxed);
+                return Ok(result);
+            }
+        }
+        self.connected.store(false, Ordering::Relaxed);
+        Err(format!("batch read failed 0x{:02x}..+{}", base, count))
+    }
+
+    /// Reads `count` consecutive holding registers starting at `base` in a
+    /// single Modbus transaction, as grouped by `group_registers`.
+    pub async fn get_holding_batch(&self, base: u16, count: u16) -> Result<Vec<u16>> {
+        if let Some(ctx) = &self.ctx {
+            if let Ok(Ok(Ok(result))) = timeout(
+                self.req_timeout,
+                ctx.lock().await.read_holding_registers(base, count),
+            )
+            .await
+            {
+                self.connected.store(true, Ordering::Relaxed);
+                return Ok(result);
+            }
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        if let Some(ctx) = &self.ctx {
+            if let Ok(Ok(Ok(result))) = timeout(
+                self.req_timeout,
+                ctx.lock().await.read_holding_registers(base, count),
+            )
+            .await
+            {
+                self.connected.store(true, Ordering::Relaxed);
+                return Ok(result);
+            }
+        }
+        self.connected.store(false, Ordering::Relaxed);
+        Err(format!("batch read failed 0x{:02x}..+{}", base, count))
+    }
+
+    /// Reads `count` consecutive input registers starting at `base` in a
+    /// single Modbus transaction, as grouped by `group_registers`.
+    pub async fn get_input_batch(&self, base: u16, count: u16) -> Result<Vec<u16>> {
+        if let Some(ctx) = &self.ctx {
+            if let Ok(Ok(Ok(result))) = timeout(
+                self.req_timeout,
+                ctx.lock().await.read_input_registers(base, count),
+            )
+            .await
+            {
+                self.connected.store(true, Ordering::Relaxed);
+                return Ok(result);
+            }
+        }
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        if let Some(ctx) = &self.ctx {
+            if let Ok(Ok(Ok(result))) = timeout(
+                self.req_timeout,
+                ctx.lock().await.read_input_registers(base, count),
+            )
+            .await
+            {
+                self.connected.store(true, Ordering::Relaxed);
+                return Ok(result);
+            }
+        }
+        self.connected.store(false, Ordering::Relaxed);
+        Err(format!("batch read failed 0x{:02x}..+{}", base, count))
+    }
+
+    /// Current link state as observed by the most recent register read; writes
+    /// do not update it.
+    pub fn is_connected(&self) -> bool {
+        self.connected.load(Ordering::Relaxed)
+    }
+
+    /// Current health of the Modbus transport (`Connected`/`Reconnecting`/`Down`),
+    /// for publishing as a diagnostic topic alongside the regular register data.
+    pub fn link_state(&self) -> LinkState {
+        self.supervisor.state()
+    }
 }
 
 fn remap_topic_from_modbus(topic: String) -> String {
     match topic.as_str() {
-        "operation_mode" => String::from(""),
+        "operation_mode" => String::from("climate/mode"),
+        "target_temp_heating_cooling_circuit1" => String::from("climate/temperature"),
         "target_temp_heating_cooling_circuit2" => String::from("dhw/temperature"),
+        "water_inlet_temperature" => String::from("climate/current_temperature"),
         "room_air_temperature_circuit2" => String::from("dhw/current_temperature"),
         &_ => topic,
     }
@@ -1,61 +1,108 @@
 pub mod modbus_to_mqtt;
 
 use crate::config::MqttConfig;
-use rumqttc::{AsyncClient, ClientError, Event, EventLoop, MqttOptions, QoS};
+use rumqttc::{AsyncClient, ClientError, Event, EventLoop, LastWill, MqttOptions, QoS};
 use std::collections::HashMap;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
+use std::time::Duration;
 use tokio::sync::mpsc;
 use tokio::sync::mpsc::{Receiver, Sender};
 
 type Callback = Arc<dyn Fn(String, String) + Send + Sync>;
+type ReconnectCallback = Arc<dyn Fn() + Send + Sync>;
 
 pub struct Client {
     base_topic: String,
+    availability_topic: String,
     mqtt_client: AsyncClient,
     callbacks: Arc<tokio::sync::RwLock<HashMap<String, Callback>>>,
+    reconnect_callbacks: Arc<tokio::sync::RwLock<Vec<ReconnectCallback>>>,
 }
 
+const AVAILABLE_PAYLOAD: &str = "ready";
+const UNAVAILABLE_PAYLOAD: &str = "lost";
+const INITIAL_RECONNECT_BACKOFF: Duration = Duration::from_secs(1);
+const MAX_RECONNECT_BACKOFF: Duration = Duration::from_secs(30);
+
 impl Client {
     pub fn new(
         config: &MqttConfig,
         shutdown_listener: Arc<AtomicBool>,
     ) -> (Self, Receiver<(String, String)>) {
+        let availability_topic = format!("{}/$state", config.topic);
+
         let mut mqtt_options =
             MqttOptions::new(&config.client_name, &config.host_name, config.host_port);
         mqtt_options.set_credentials(&config.username, &config.password);
+        mqtt_options.set_last_will(LastWill::new(
+            &availability_topic,
+            UNAVAILABLE_PAYLOAD,
+            QoS::AtLeastOnce,
+            true,
+        ));
 
         let (client, event_loop) = AsyncClient::new(mqtt_options, config.channel_size);
 
         let instance = Client {
             base_topic: config.topic.clone(),
+            availability_topic,
             mqtt_client: client,
             callbacks: Arc::new(tokio::sync::RwLock::new(HashMap::new())),
+            reconnect_callbacks: Arc::new(tokio::sync::RwLock::new(Vec::new())),
         };
         let (sender, receiver) = mpsc::channel(100);
         Self::start_event_loop(&instance, sender, event_loop, shutdown_listener);
         (instance, receiver)
     }
 
+    /// Registers a callback invoked after the event loop recovers from a
+    /// connection error, so callers can re-publish retained state (discovery
+    /// messages, subscriptions) that the broker may have lost.
+    pub async fn on_reconnect<F>(&self, callback: F)
+    where
+        F: Fn() + Send + Sync + 'static,
+    {
+        self.reconnect_callbacks.write().await.push(Arc::new(callback));
+    }
+
     fn start_event_loop(
         &self,
         sender: Sender<(String, String)>,
         mut event_loop: EventLoop,
         shutdown_listener: Arc<AtomicBool>,
     ) {
+        let reconnect_callbacks = self.reconnect_callbacks.clone();
         tokio::spawn(async move {
+            let mut backoff = INITIAL_RECONNECT_BACKOFF;
+            let mut recovering = false;
             while !shutdown_listener.load(Ordering::Relaxed) {
-                if let Ok(event) = event_loop.poll().await {
-                    match event {
-                        Event::Incoming(rumqttc::Packet::Publish(publish)) => {
-                            let topic = publish.topic.clone();
-                            let payload = String::from_utf8_lossy(&publish.payload).to_string();
-                            if let Err(err) = sender.send((topic, payload.to_string())).await {
-                                eprintln!("Error sending message: {}", err);
+                match event_loop.poll().await {
+                    Ok(event) => {
+                        if recovering {
+                            recovering = false;
+                            backoff = INITIAL_RECONNECT_BACKOFF;
+                            for callback in reconnect_callbacks.read().await.iter() {
+                                callback();
                             }
                         }
-                        Event::Outgoing(_) => {}
-                        _ => {}
+                        match event {
+                            Event::Incoming(rumqttc::Packet::Publish(publish)) => {
+                                let topic = publish.topic.clone();
+                                let payload = String::from_utf8_lossy(&publish.payload).to_string();
+                                if let Err(err) = sender.send((topic, payload.to_string())).await {
+                                    eprintln!("Error sending message: {}", err);
+                                }
+                            }
+                            Event::Outgoing(_) => {}
+                            _ => {}
+                        }
+                    }
+                    Err(err) => {
+                        log::error!(target: "mqtt-client", "mqtt event loop error, retrying in {backoff:?}: {err}");
+                        recovering = true;
+                        tokio::time::sleep(backoff).await;
+                        backoff = (backoff * 2).min(MAX_RECONNECT_BACKOFF);
                     }
                 }
             }
@@ -112,6 +159,29 @@ impl Client {
     {
         self.mqtt_client.subscribe(topic, QoS::AtLeastOnce).await
     }
+
+    /// Publishes `ready`/`lost` (retained) on the availability topic backing the
+    /// MQTT Last Will, so Home Assistant entities track the bridge's link state.
+    pub async fn publish_availability(&self, available: bool) -> Option<String> {
+        let payload = if available {
+            AVAILABLE_PAYLOAD
+        } else {
+            UNAVAILABLE_PAYLOAD
+        };
+        match self
+            .mqtt_client
+            .publish(
+                self.availability_topic.clone(),
+                QoS::AtLeastOnce,
+                true,
+                payload,
+            )
+            .await
+        {
+            Ok(_) => None,
+            Err(e) => Some(format!("Error publishing availability: {:?}", e)),
+        }
+    }
 }
 
 impl Drop for Client {
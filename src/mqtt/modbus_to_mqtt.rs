@@ -1,3 +1,4 @@
+use crate::config::{find_register, DueScheduler, RegisterKind, RegisterMap};
 use crate::{Register, SignalListener};
 use std::sync::atomic::AtomicBool;
 use std::sync::Arc;
@@ -5,12 +6,14 @@ use std::sync::Arc;
 pub fn start_publish_task<T>(
     mqtt_client: Arc<super::Client>,
     modbus_frame_listener: &T,
+    registers: Vec<RegisterMap>,
     signal: Arc<AtomicBool>,
 ) where
     T: SignalListener,
 {
     let mut modbus_rx = modbus_frame_listener.register_receiver();
     tokio::spawn(async move {
+        let mut scheduler = DueScheduler::new(&registers);
         loop {
             if signal.load(std::sync::atomic::Ordering::Relaxed) {
                 break;
@@ -23,6 +26,9 @@ pub fn start_publish_task<T>(
                     continue;
                 }
             };
+            if !register_due(&mut scheduler, &reg) {
+                continue;
+            }
             match reg {
                 Register::Coil(reg) => {
                     if let Some(error) = mqtt_client
@@ -41,28 +47,18 @@ pub fn start_publish_task<T>(
                     }
                 }
                 Register::Holding(reg) => {
+                    let payload = decoded_payload(&registers, RegisterKind::Holding, reg.0, &reg.1);
                     if let Some(error) = mqtt_client
-                        .publish(
-                            format!("holding/{:03x}", reg.0),
-                            reg.1
-                                .iter()
-                                .flat_map(|&num| num.to_le_bytes())
-                                .collect::<Vec<_>>(),
-                        )
+                        .publish(format!("holding/{:03x}", reg.0), payload)
                         .await
                     {
                         log::error!(target: "mqtt-client", "failed to publish mqtt msg: {error}");
                     }
                 }
                 Register::Input(reg) => {
+                    let payload = decoded_payload(&registers, RegisterKind::Input, reg.0, &reg.1);
                     if let Some(error) = mqtt_client
-                        .publish(
-                            format!("input/{:03x}", reg.0),
-                            reg.1
-                                .iter()
-                                .flat_map(|&num| num.to_le_bytes())
-                                .collect::<Vec<_>>(),
-                        )
+                        .publish(format!("input/{:03x}", reg.0), payload)
                         .await
                     {
                         log::error!(target: "mqtt-client", "failed to publish mqtt msg: {error}");
@@ -72,3 +68,32 @@ pub fn start_publish_task<T>(
         }
     });
 }
+
+/// Maps a received frame to its `(kind, address)` scheduling key and asks the
+/// scheduler whether it's due for publishing.
+fn register_due(scheduler: &mut DueScheduler, reg: &Register) -> bool {
+    let (kind, address) = match reg {
+        Register::Coil(reg) => (RegisterKind::Coil, reg.0),
+        Register::Discrete(reg) => (RegisterKind::Discrete, reg.0),
+        Register::Holding(reg) => (RegisterKind::Holding, reg.0),
+        Register::Input(reg) => (RegisterKind::Input, reg.0),
+    };
+    scheduler.due(kind, address)
+}
+
+/// Decodes a holding/input register against its configured mapping (scale + word
+/// order) into a decimal string; unmapped registers fall back to raw little-endian bytes.
+fn decoded_payload(
+    registers: &[RegisterMap],
+    kind: RegisterKind,
+    address: u16,
+    words: &[u16],
+) -> Vec<u8> {
+    match find_register(registers, kind, address) {
+        Some(reg) => reg.decode(words).to_string().into_bytes(),
+        None => words
+            .iter()
+            .flat_map(|&num| num.to_le_bytes())
+            .collect::<Vec<_>>(),
+    }
+}
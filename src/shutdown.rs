@@ -0,0 +1,124 @@
+use log::error;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use tokio::signal;
+use tokio::sync::{watch, Mutex};
+use tokio::task::JoinHandle;
+
+/// Coordinates a single shutdown signal across every spawned worker and lets
+/// the caller wait for all of them to finish (in-flight transaction drained,
+/// serial port released) before the process exits. Replaces the previous
+/// pattern of each worker polling its own `Arc<AtomicBool>` and the HTTP
+/// server separately registering its own Ctrl-C/SIGTERM handler.
+#[derive(Clone)]
+pub struct Shutdown {
+    /// Kept for workers (MQTT event loop, HA bridge) not yet migrated off the
+    /// polled-bool pattern; flipped at the same instant as `tx`.
+    flag: Arc<AtomicBool>,
+    tx: watch::Sender<bool>,
+    tasks: Arc<Mutex<Vec<JoinHandle<()>>>>,
+}
+
+/// A worker's view of `Shutdown`: a signal it can `recv()` or poll, cloneable
+/// so each worker task can hold its own.
+#[derive(Clone)]
+pub struct ShutdownListener {
+    rx: watch::Receiver<bool>,
+}
+
+impl Shutdown {
+    pub fn new() -> Self {
+        let (tx, _rx) = watch::channel(false);
+        Self {
+            flag: Arc::new(AtomicBool::new(false)),
+            tx,
+            tasks: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// A listener for workers that have migrated to the `recv()`-based style.
+    pub fn listener(&self) -> ShutdownListener {
+        ShutdownListener {
+            rx: self.tx.subscribe(),
+        }
+    }
+
+    /// The shared flag for workers still on the older polled-bool pattern.
+    pub fn legacy_flag(&self) -> Arc<AtomicBool> {
+        self.flag.clone()
+    }
+
+    /// Registers a spawned worker so `wait_for_tasks` blocks until it exits.
+    pub async fn register(&self, handle: JoinHandle<()>) {
+        self.tasks.lock().await.push(handle);
+    }
+
+    /// Flips the shared signal so every `ShutdownListener` and the legacy flag
+    /// observe shutdown at the same instant.
+    pub fn trigger(&self) {
+        self.flag.store(true, Ordering::Relaxed);
+        let _ = self.tx.send(true);
+    }
+
+    /// Waits for Ctrl-C or SIGTERM, then triggers shutdown. Intended to be
+    /// spawned once, in parallel with the HTTP server and poll workers, so
+    /// none of them registers its own competing signal handler.
+    pub async fn listen_for_signal(&self) {
+        wait_for_signal().await;
+        self.trigger();
+    }
+
+    /// Awaits every registered worker task, draining in-flight Modbus
+    /// transactions before returning.
+    pub async fn wait_for_tasks(&self) {
+        let mut tasks = self.tasks.lock().await;
+        for task in tasks.drain(..) {
+            if let Err(err) = task.await {
+                error!(target: "shutdown", "worker task panicked: {}", err);
+            }
+        }
+    }
+}
+
+impl Default for Shutdown {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ShutdownListener {
+    pub fn is_shutdown(&self) -> bool {
+        *self.rx.borrow()
+    }
+
+    /// Resolves once shutdown has been triggered; pair with `tokio::select!`
+    /// alongside a worker's normal sleep/poll so it can finish its current
+    /// transaction before exiting.
+    pub async fn recv(&mut self) {
+        let _ = self.rx.wait_for(|shutdown| *shutdown).await;
+    }
+}
+
+async fn wait_for_signal() {
+    let ctrl_c = async {
+        signal::ctrl_c().await.unwrap_or_else(|e| {
+            error!(target: "shutdown", "failed to install Ctrl+C handler: {}", e);
+            std::process::exit(1);
+        });
+    };
+
+    let terminate = async {
+        signal::unix::signal(signal::unix::SignalKind::terminate())
+            .unwrap_or_else(|e| {
+                error!(target: "shutdown", "failed to install terminate signal handler: {}", e);
+                std::process::exit(1);
+            })
+            .recv()
+            .await;
+    };
+
+    tokio::select! {
+        _ = ctrl_c => {},
+        _ = terminate => {},
+    }
+}